@@ -11,40 +11,71 @@ complete -c menyoki -n "__fish_use_subcommand" -f -a "capture" -d 'Capture an im
 complete -c menyoki -n "__fish_use_subcommand" -f -a "edit" -d 'Edit an image'
 complete -c menyoki -n "__fish_use_subcommand" -f -a "analyze" -d 'Analyze an image'
 complete -c menyoki -n "__fish_use_subcommand" -f -a "view" -d 'View an image'
+complete -c menyoki -n "__fish_use_subcommand" -f -a "list" -d 'List the windows and monitors'
 complete -c menyoki -n "__fish_use_subcommand" -f -a "misc" -d 'Perform miscellaneous operations'
 complete -c menyoki -n "__fish_use_subcommand" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l viewport -d 'Record an area that follows the mouse pointer'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l easing -d 'Set the easing factor for moving the viewport'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l keys-corner -d 'Set the corner to show the pressed keys on' -r -f -a "top-left top-right bottom-left bottom-right"
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l action-keys -d 'Set the action keys'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l cancel-keys -d 'Set the cancel keys'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l pause-keys -d 'Set the keys for pausing/resuming the recording'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s b -l border -d 'Set the border width'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s p -l padding -d 'Set the record area padding'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s s -l size -d 'Set the record area size'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s d -l duration -d 'Set the duration for recording'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l replay -d 'Save only the last S seconds of the recording'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l sequence -d 'Write the frames to a directory as an image sequence'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l sequence-format -d 'Set the image format of the sequence' -r -f -a "png qoi ppm"
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s c -l countdown -d 'Set the countdown before recording'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s t -l timeout -d 'Set the timeout for window selection'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s i -l interval -d 'Set the refresh interval for window selection'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l every -d 'Capture an image periodically (e.g. 30s, 5m)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l count -d 'Set the number of periodic captures'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l skip-similar -d 'Skip the images that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l animate -d 'Save the periodic captures as a GIF with the given FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l font -d 'Set the font to use for window selection'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l monitor -d 'Set the monitor to record as root window'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l window-id -d 'Set the ID of the window to record'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l window-title -d 'Set the title of the window to record'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l window-class -d 'Set the class of the window to record'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l pid -d 'Set the process ID of the window to record'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l wait-for-window -d 'Wait for a window that matches the title regex or class'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s r -l root -d 'Record the root window'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s f -l focus -d 'Record the focused window'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l select -d 'Select the window to record'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l parent -d 'Record the parent of the window'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l with-alpha -d 'Record with the alpha channel'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l cursor -d 'Record the mouse cursor'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l composite -d 'Record the window contents even if obscured'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l drag -d 'Select the area to record by dragging the mouse'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l freeze -d 'Freeze the screen while selecting the area'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l session -d 'Keep selecting and capturing until cancelled'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l follow -d 'Follow the window if it is moved or resized'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l show-keys -d 'Show the pressed keys on the recording'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l show-clicks -d 'Highlight the mouse clicks on the recording'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -l no-keys -d 'Disable the action keys while recording'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s m -l mouse -d 'Select the window with mouse click'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -l all-monitors -d 'Record each monitor to a separate file'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "gif" -d 'Use the GIF encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "apng" -d 'Use the APNG encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "av1" -d 'Use the AV1 encoder (IVF/WebM)'
+complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "avi" -d 'Use the Motion JPEG encoder (AVI)'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "save" -d 'Save the output file(s)'
 complete -c menyoki -n "__fish_seen_subcommand_from record" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l preset -d 'Set the encoder speed preset (0-10)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-end -d 'Cut the end of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l fast -d 'Encode 3 times faster (gifski)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s n -l no-sort -d 'Use frames in the order given'
@@ -62,11 +93,14 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l preset -d 'Set the encoder speed preset (0-10)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-end -d 'Cut the end of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l fast -d 'Encode 3 times faster (gifski)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s n -l no-sort -d 'Use frames in the order given'
@@ -81,6 +115,56 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l preset -d 'Set the encoder speed preset (0-10)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l preset -d 'Set the encoder speed preset (0-10)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
@@ -230,11 +314,14 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l preset -d 'Set the encoder speed preset (0-10)'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l cut-end -d 'Cut the end of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from make" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from make" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -l fast -d 'Encode 3 times faster (gifski)'
 complete -c menyoki -n "__fish_seen_subcommand_from make" -s n -l no-sort -d 'Use frames in the order given'
@@ -249,24 +336,49 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l viewport -d 'Record an area that follows the mouse pointer'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l easing -d 'Set the easing factor for moving the viewport'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l keys-corner -d 'Set the corner to show the pressed keys on' -r -f -a "top-left top-right bottom-left bottom-right"
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l action-keys -d 'Set the action keys'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l cancel-keys -d 'Set the cancel keys'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l pause-keys -d 'Set the keys for pausing/resuming the recording'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s b -l border -d 'Set the border width'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s p -l padding -d 'Set the capture area padding'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s s -l size -d 'Set the capture area size'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s d -l duration -d 'Set the duration for recording'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l replay -d 'Save only the last S seconds of the recording'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l sequence -d 'Write the frames to a directory as an image sequence'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l sequence-format -d 'Set the image format of the sequence' -r -f -a "png qoi ppm"
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s c -l countdown -d 'Set the countdown before capturing'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s t -l timeout -d 'Set the timeout for window selection'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s i -l interval -d 'Set the refresh interval for window selection'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l every -d 'Capture an image periodically (e.g. 30s, 5m)'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l count -d 'Set the number of periodic captures'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l skip-similar -d 'Skip the images that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l animate -d 'Save the periodic captures as a GIF with the given FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l font -d 'Set the font to use for window selection'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l monitor -d 'Set the monitor to capture as root window'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l window-id -d 'Set the ID of the window to capture'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l window-title -d 'Set the title of the window to capture'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l window-class -d 'Set the class of the window to capture'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l pid -d 'Set the process ID of the window to capture'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l wait-for-window -d 'Wait for a window that matches the title regex or class'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s r -l root -d 'Capture the root window'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s f -l focus -d 'Capture the focused window'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l select -d 'Select the window to capture'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l parent -d 'Capture the parent of the window'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l with-alpha -d 'Capture with the alpha channel'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l cursor -d 'Capture the mouse cursor'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l composite -d 'Capture the window contents even if obscured'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l drag -d 'Select the area to capture by dragging the mouse'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l freeze -d 'Freeze the screen while selecting the area'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l session -d 'Keep selecting and capturing until cancelled'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l follow -d 'Follow the window if it is moved or resized'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l show-keys -d 'Show the pressed keys on the recording'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l show-clicks -d 'Highlight the mouse clicks on the recording'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -l no-keys -d 'Disable the action keys while recording'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s m -l mouse -d 'Select the window with mouse click'
+complete -c menyoki -n "__fish_seen_subcommand_from capture" -l all-monitors -d 'Capture each monitor to a separate file'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from capture" -f -a "png" -d 'Use the PNG encoder'
@@ -422,6 +534,8 @@ complete -c menyoki -n "__fish_seen_subcommand_from edit" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "gif" -d 'Use the GIF encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "apng" -d 'Use the APNG encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "av1" -d 'Use the AV1 encoder (IVF/WebM)'
+complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "avi" -d 'Use the Motion JPEG encoder (AVI)'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "png" -d 'Use the PNG encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "jpg" -d 'Use the JPG encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "webp" -d 'Use the WebP encoder'
@@ -437,11 +551,14 @@ complete -c menyoki -n "__fish_seen_subcommand_from edit" -f -a "help" -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l preset -d 'Set the encoder speed preset (0-10)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l cut-end -d 'Cut the end of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from gif" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -l fast -d 'Encode 3 times faster (gifski)'
 complete -c menyoki -n "__fish_seen_subcommand_from gif" -s n -l no-sort -d 'Use frames in the order given'
@@ -459,11 +576,14 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s f -l fps -d 'Set the FPS'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s q -l quality -d 'Set the frame quality (1-100)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l preset -d 'Set the encoder speed preset (0-10)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s s -l speed -d 'Set the animation speed'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-beginning -d 'Cut the beginning of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l cut-end -d 'Cut the end of the animation'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s d -l dir -d 'Set the directory to read frames'
-complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng"
+complete -c menyoki -n "__fish_seen_subcommand_from apng" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l gifski -d 'Use the gifski encoder'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -l fast -d 'Encode 3 times faster (gifski)'
 complete -c menyoki -n "__fish_seen_subcommand_from apng" -s n -l no-sort -d 'Use frames in the order given'
@@ -478,6 +598,56 @@ complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print
 complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
 complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l preset -d 'Set the encoder speed preset (0-10)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from av1" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s f -l fps -d 'Set the FPS'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s q -l quality -d 'Set the frame quality (1-100)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s r -l repeat -d 'Set the number of repetitions'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l memory-limit -d 'Set the memory limit for storing frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l collapse -d 'Merge the consecutive frames that differ in at most PIXELS'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l preset -d 'Set the encoder speed preset (0-10)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s s -l speed -d 'Set the animation speed'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-beginning -d 'Cut the beginning of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l cut-end -d 'Cut the end of the animation'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s d -l dir -d 'Set the directory to read frames'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l format -d 'Set the animation format' -r -f -a "gif apng av1 avi"
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l gifski -d 'Use the gifski encoder'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -l fast -d 'Encode 3 times faster (gifski)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s n -l no-sort -d 'Use frames in the order given'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "save" -d 'Save the output file(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from avi" -f -a "help" -d 'Prints this message or the help of the given subcommand(s)'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s d -l date -d 'Add formatted date/time to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s e -l with-extension -d 'Always save the file with an extension'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s t -l timestamp -d 'Add Unix timestamp to the file name'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from save" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s h -l help -d 'Prints help information'
+complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from png" -s c -l compression -d 'Set the compression level' -r -f -a "default fast best"
 complete -c menyoki -n "__fish_seen_subcommand_from png" -s f -l filter -d 'Set the filter algorithm' -r -f -a "none sub up avg paeth"
 complete -c menyoki -n "__fish_seen_subcommand_from png" -s h -l help -d 'Print help information'
@@ -618,6 +788,11 @@ complete -c menyoki -n "__fish_seen_subcommand_from help" -s V -l version -d 'Pr
 complete -c menyoki -n "__fish_seen_subcommand_from view" -s t -l transparent -d 'Display transparent image with transparent background'
 complete -c menyoki -n "__fish_seen_subcommand_from view" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from view" -s V -l version -d 'Prints version information'
+complete -c menyoki -n "__fish_seen_subcommand_from list" -s f -l format -d 'Set the output format' -r -f -a "table json"
+complete -c menyoki -n "__fish_seen_subcommand_from list" -s w -l windows -d 'List only the windows'
+complete -c menyoki -n "__fish_seen_subcommand_from list" -s m -l monitors -d 'List only the monitors'
+complete -c menyoki -n "__fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c menyoki -n "__fish_seen_subcommand_from list" -s V -l version -d 'Prints version information'
 complete -c menyoki -n "__fish_seen_subcommand_from misc" -s g -l gen-completions -d 'Generate completions for the specified shell' -r -f -a "bash fish zsh powershell elvish"
 complete -c menyoki -n "__fish_seen_subcommand_from misc" -s h -l help -d 'Print help information'
 complete -c menyoki -n "__fish_seen_subcommand_from misc" -s V -l version -d 'Prints version information'
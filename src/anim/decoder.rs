@@ -1,9 +1,10 @@
 use crate::anim::settings::AnimSettings;
-use crate::anim::Frames;
+use crate::anim::store::FrameStore;
 use crate::app::{AppError, AppResult};
 use crate::edit::ImageOps;
 use image::Frame;
 use std::io::{self, Write};
+use std::time::Duration;
 
 /* Animation decoder and settings */
 pub struct AnimDecoder<'a> {
@@ -24,52 +25,58 @@ impl<'a> AnimDecoder<'a> {
 	}
 
 	/**
-	 * Update frames to cut the duration.
+	 * Get the frames with their delays after applying the speed and cut.
 	 *
 	 * @param  frames
+	 * @param  speed
 	 * @param  cut
-	 * @param  fps
-	 * @return Vector of Frame
+	 * @return Vector of Tuple
 	 */
-	fn cut_duration(
-		frames: &mut Vec<Frame>,
+	fn get_timed_frames(
+		frames: Vec<Frame>,
+		speed: f32,
 		cut: (f32, f32),
-		fps: u32,
-	) -> Vec<Frame> {
-		if cut != (0., 0.) {
-			let (start, end) = cut;
-			let frame_delay = 1000_u32.checked_div(fps).unwrap_or_default() as f32;
-			frames
-				.drain(
-					((start / frame_delay) as u32)
-						.try_into()
-						.unwrap_or_default()
-						..frames.len().saturating_sub((end / frame_delay) as usize),
-				)
-				.collect()
-		} else {
-			frames.to_vec()
-		}
+	) -> Vec<(Frame, Duration)> {
+		let speed = if speed > 0. { f64::from(speed) } else { 1. };
+		let frames = frames
+			.into_iter()
+			.map(|frame| {
+				let (numer, denom) = frame.delay().numer_denom_ms();
+				let delay = f64::from(numer) / f64::from(denom.max(1)) / speed;
+				(frame, Duration::from_secs_f64(delay / 1e3))
+			})
+			.collect::<Vec<(Frame, Duration)>>();
+		let total = frames.iter().map(|(_, delay)| *delay).sum::<Duration>();
+		let start = Duration::from_secs_f32(cut.0.max(0.) / 1e3);
+		let end = total.saturating_sub(Duration::from_secs_f32(cut.1.max(0.) / 1e3));
+		let mut time = Duration::ZERO;
+		frames
+			.into_iter()
+			.filter(|(_, delay)| {
+				let frame_time = time;
+				time += *delay;
+				(cut.0 <= 0. || frame_time + *delay > start)
+					&& (cut.1 <= 0. || frame_time < end)
+			})
+			.collect()
 	}
 
 	/**
 	 * Update and return the frames.
 	 *
 	 * @param  frames
-	 * @return Frames (Result)
+	 * @return FrameStore (Result)
 	 */
-	pub fn update_frames(mut self, mut frames: Vec<Frame>) -> AppResult<Frames> {
+	pub fn update_frames(mut self, frames: Vec<Frame>) -> AppResult<FrameStore> {
 		let first_frame = frames.first().ok_or_else(|| {
 			AppError::FrameError(String::from("No frames found to process"))
 		})?;
 		self.imageops
 			.init(first_frame.clone().into_buffer().dimensions());
-		let fps = ((1e3 / first_frame.delay().numer_denom_ms().0 as f32)
-			* self.settings.speed) as u32;
-		debug!("FPS: {:?}", fps);
-		let frames = Self::cut_duration(&mut frames, self.settings.cut, fps);
-		let mut images = Vec::new();
-		for (i, frame) in frames.iter().enumerate() {
+		let frames =
+			Self::get_timed_frames(frames, self.settings.speed, self.settings.cut);
+		let mut images = FrameStore::new(self.settings.memory_limit);
+		for (i, (frame, delay)) in frames.iter().enumerate() {
 			let percentage = ((i + 1) as f64 / frames.len() as f64) * 100.;
 			info!("Processing the frames... ({:.1}%)\r", percentage);
 			debug!(
@@ -83,10 +90,11 @@ impl<'a> AnimDecoder<'a> {
 				self.imageops
 					.process(frame.clone().into_buffer())
 					.get_image(),
-			);
+				*delay,
+			)?;
 		}
 		info!("\n");
-		Ok((images, fps))
+		Ok(images)
 	}
 }
 
@@ -122,8 +130,26 @@ mod tests {
 				),
 			])
 			.unwrap();
-		assert_eq!(2, frames.1);
-		assert_eq!(1, frames.0.len());
-		assert_eq!(Geometry::new(0, 0, 2, 2), frames.0[0].geometry);
+		assert_eq!(1, frames.len());
+		assert_eq!(Duration::from_millis(5), frames.get_delay(0));
+		assert_eq!(Geometry::new(0, 0, 2, 2), frames.get(0).unwrap().geometry);
+		let frames = (1..=4)
+			.map(|i| {
+				Frame::from_parts(
+					RgbaImage::new(1, 1),
+					0,
+					0,
+					Delay::from_numer_denom_ms(i * 100, 1),
+				)
+			})
+			.collect::<Vec<Frame>>();
+		assert_eq!(
+			vec![Duration::from_millis(200), Duration::from_millis(300)],
+			AnimDecoder::get_timed_frames(frames.clone(), 1., (150., 450.))
+				.into_iter()
+				.map(|(_, delay)| delay)
+				.collect::<Vec<Duration>>()
+		);
+		assert_eq!(4, AnimDecoder::get_timed_frames(frames, 0., (0., 0.)).len());
 	}
 }
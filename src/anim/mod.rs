@@ -1,17 +1,43 @@
 pub mod decoder;
 pub mod settings;
+pub mod store;
 
+use crate::app::{AppError, AppResult};
+use crate::image::geometry::Geometry;
 use crate::image::Image;
 use std::fmt;
+use std::time::Duration;
 
-/* Images to encode and FPS value */
-pub type Frames = (Vec<Image>, u32);
+/* Image to encode and its delay */
+pub type TimedFrame = (Image, Duration);
+
+/**
+ * Check if the frame has the same size as the animation.
+ *
+ * @param  image
+ * @param  geometry
+ * @return Result
+ */
+pub fn check_frame_size(image: &Image, geometry: Geometry) -> AppResult<()> {
+	if (image.geometry.width, image.geometry.height)
+		== (geometry.width, geometry.height)
+	{
+		Ok(())
+	} else {
+		Err(AppError::FrameError(format!(
+			"Frame size does not match: {}x{}",
+			image.geometry.width, image.geometry.height
+		)))
+	}
+}
 
 /* Animation format */
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AnimFormat {
 	Gif,
 	Apng,
+	Av1,
+	Avi,
 }
 
 /* Display implementation for user-facing output */
@@ -59,6 +85,8 @@ impl AnimMode {
 			Self::Record(format) | Self::Edit(format) => match format {
 				AnimFormat::Gif => "Use the GIF encoder",
 				AnimFormat::Apng => "Use the APNG encoder",
+				AnimFormat::Av1 => "Use the AV1 encoder (IVF/WebM)",
+				AnimFormat::Avi => "Use the Motion JPEG encoder (AVI)",
 			},
 			Self::Make => "Make an animation from frames",
 		}
@@ -83,6 +111,7 @@ impl fmt::Display for AnimMode {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use image::Rgba;
 	use pretty_assertions::assert_eq;
 	#[test]
 	fn test_anim_mode() {
@@ -103,5 +132,28 @@ mod tests {
 		assert!(!anim_mode.has_format(AnimFormat::Apng));
 		assert_eq!("Make an animation from frames", anim_mode.get_description());
 		assert_eq!("make", anim_mode.to_string().as_str());
+		let anim_mode = AnimMode::Record(AnimFormat::Av1);
+		assert_eq!(
+			"Use the AV1 encoder (IVF/WebM)",
+			anim_mode.get_description()
+		);
+		assert_eq!("av1", anim_mode.to_string().as_str());
+		let anim_mode = AnimMode::Edit(AnimFormat::Avi);
+		assert!(anim_mode.has_format(AnimFormat::Avi));
+		assert_eq!(
+			"Use the Motion JPEG encoder (AVI)",
+			anim_mode.get_description()
+		);
+		assert_eq!("avi", anim_mode.to_string().as_str());
+	}
+	#[test]
+	fn test_check_frame_size() {
+		let image = Image::new(
+			vec![Rgba::from([0, 0, 0, 255]); 6],
+			false,
+			Geometry::new(0, 0, 3, 2),
+		);
+		assert!(check_frame_size(&image, Geometry::new(10, 10, 3, 2)).is_ok());
+		assert!(check_frame_size(&image, Geometry::new(0, 0, 2, 3)).is_err());
 	}
 }
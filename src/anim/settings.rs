@@ -4,6 +4,7 @@ use crate::file::format::FileFormat;
 use crate::file::File;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /* Animation and frame settings */
 #[derive(Debug)]
@@ -15,6 +16,9 @@ pub struct AnimSettings {
 	pub cut: (f32, f32),
 	pub frames: Vec<PathBuf>,
 	pub gifski: (bool, bool),
+	pub preset: u8,
+	pub memory_limit: u64,
+	pub collapse: Option<u32>,
 }
 
 /* Default initialization values for AnimSettings */
@@ -28,6 +32,9 @@ impl Default for AnimSettings {
 			cut: (0., 0.),
 			frames: Vec::new(),
 			gifski: (false, false),
+			preset: 8,
+			memory_limit: 1024 * 1024 * 1024,
+			collapse: None,
 		}
 	}
 }
@@ -43,8 +50,12 @@ impl AnimSettings {
 	 * @param  cut
 	 * @param  frames
 	 * @param  gifski
+	 * @param  preset
+	 * @param  memory_limit
+	 * @param  collapse (Option)
 	 * @return AnimSettings
 	 */
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		fps: u32,
 		repeat: i32,
@@ -53,6 +64,9 @@ impl AnimSettings {
 		cut: (f32, f32),
 		frames: Vec<PathBuf>,
 		gifski: (bool, bool),
+		preset: u8,
+		memory_limit: u64,
+		collapse: Option<u32>,
 	) -> Self {
 		Self {
 			fps,
@@ -62,6 +76,9 @@ impl AnimSettings {
 			cut,
 			frames,
 			gifski,
+			preset,
+			memory_limit,
+			collapse,
 		}
 	}
 
@@ -109,6 +126,12 @@ impl AnimSettings {
 					matches.is_present("gifski") || matches.is_present("fast"),
 					matches.is_present("fast"),
 				),
+				parser.parse("preset", Self::default().preset).min(10),
+				parser.parse("memory-limit", Self::default().memory_limit >> 20)
+					<< 20,
+				matches
+					.value_of("collapse")
+					.and_then(|threshold| threshold.parse().ok()),
 			),
 			None => Self::default(),
 		}
@@ -147,6 +170,15 @@ impl AnimSettings {
 		values.into_iter().map(PathBuf::from).collect()
 	}
 
+	/**
+	 * Get the delay between the frames with respect to FPS.
+	 *
+	 * @return Duration
+	 */
+	pub fn get_frame_delay(&self) -> Duration {
+		Duration::from_secs_f64(1. / f64::from(self.fps.max(1)))
+	}
+
 	/**
 	 * Map the given number from a range to another range.
 	 *
@@ -256,6 +288,7 @@ mod tests {
 			.arg(Arg::with_name("quality").long("quality").takes_value(true))
 			.arg(Arg::with_name("gifski").long("gifski"))
 			.arg(Arg::with_name("fast").long("fast"))
+			.arg(Arg::with_name("preset").long("preset").takes_value(true))
 			.arg(Arg::with_name("speed").long("speed").takes_value(true))
 			.arg(
 				Arg::with_name("cut-beginning")
@@ -263,6 +296,16 @@ mod tests {
 					.takes_value(true),
 			)
 			.arg(Arg::with_name("cut-end").long("cut-end").takes_value(true))
+			.arg(
+				Arg::with_name("memory-limit")
+					.long("memory-limit")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("collapse")
+					.long("collapse")
+					.takes_value(true),
+			)
 			.get_matches_from(vec![
 				"test",
 				"--fps",
@@ -273,28 +316,44 @@ mod tests {
 				"10",
 				"--gifski",
 				"--fast",
+				"--preset",
+				"4",
 				"--speed",
 				"1.1",
 				"--cut-beginning",
 				"0.9",
 				"--cut-end",
 				"0.8",
+				"--memory-limit",
+				"64",
+				"--collapse",
+				"10",
 			]);
 		let anim_settings = AnimSettings::from_parser(ArgParser::from_args(&args));
 		assert_eq!(15, anim_settings.fps);
+		assert_eq!(
+			Duration::from_secs_f64(1. / 15.),
+			anim_settings.get_frame_delay()
+		);
 		assert_eq!(4, anim_settings.repeat);
 		assert_eq!(10, anim_settings.quality);
 		assert_eq!(true, anim_settings.gifski.0);
 		assert_eq!(true, anim_settings.gifski.1);
+		assert_eq!(4, anim_settings.preset);
 		assert_eq!(1.1, anim_settings.speed);
 		assert_eq!((900., 800.), anim_settings.cut);
+		assert_eq!(64 * 1024 * 1024, anim_settings.memory_limit);
+		assert_eq!(Some(10), anim_settings.collapse);
 		let anim_settings = AnimSettings::from_parser(ArgParser::new(None));
 		assert_eq!(-1, anim_settings.repeat);
 		assert_eq!(75, anim_settings.quality);
 		assert_eq!(false, anim_settings.gifski.0);
 		assert_eq!(false, anim_settings.gifski.1);
+		assert_eq!(8, anim_settings.preset);
 		assert_eq!(1.0, anim_settings.speed);
 		assert_eq!((0., 0.), anim_settings.cut);
+		assert_eq!(1024 * 1024 * 1024, anim_settings.memory_limit);
+		assert_eq!(None, anim_settings.collapse);
 	}
 	#[test]
 	fn test_split_settings() {
@@ -0,0 +1,374 @@
+use crate::anim::TimedFrame;
+use crate::app::{AppError, AppResult};
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/* Maximum number of frames that are waiting to be encoded */
+pub const STREAM_BUFFER: usize = 60;
+/* Number of the temporary files that are created */
+static FILE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/* Frame that is kept in memory, written to the temporary file or sent */
+#[derive(Debug)]
+enum StoredFrame {
+	Memory(Image),
+	Disk {
+		offset: u64,
+		size: u64,
+		alpha_channel: bool,
+		geometry: Geometry,
+	},
+	Sent(Geometry),
+}
+
+impl StoredFrame {
+	/**
+	 * Get the geometry of the frame.
+	 *
+	 * @return Geometry
+	 */
+	fn get_geometry(&self) -> Geometry {
+		match self {
+			Self::Memory(image) => image.geometry,
+			Self::Disk { geometry, .. } => *geometry,
+			Self::Sent(geometry) => *geometry,
+		}
+	}
+}
+
+/* Frame storage that spills to a compressed temporary file after the memory limit */
+#[derive(Debug)]
+pub struct FrameStore {
+	frames: Vec<(StoredFrame, Duration)>,
+	memory_limit: u64,
+	memory_usage: u64,
+	file: Option<(PathBuf, File)>,
+	file_size: u64,
+	sender: Option<mpsc::SyncSender<TimedFrame>>,
+}
+
+impl FrameStore {
+	/**
+	 * Create a new FrameStore object.
+	 *
+	 * @param  memory_limit
+	 * @return FrameStore
+	 */
+	pub fn new(memory_limit: u64) -> Self {
+		Self {
+			frames: Vec::new(),
+			memory_limit,
+			memory_usage: 0,
+			file: None,
+			file_size: 0,
+			sender: None,
+		}
+	}
+
+	/**
+	 * Create a new FrameStore object that sends the frames to the encoder.
+	 *
+	 * @param  sender
+	 * @return FrameStore
+	 */
+	pub fn with_sender(sender: mpsc::SyncSender<TimedFrame>) -> Self {
+		let mut store = Self::new(0);
+		store.sender = Some(sender);
+		store
+	}
+
+	/**
+	 * Create a new FrameStore object from the given frames.
+	 *
+	 * @param  frames
+	 * @param  memory_limit
+	 * @return FrameStore (Result)
+	 */
+	#[cfg(test)]
+	pub fn from_frames(
+		frames: Vec<TimedFrame>,
+		memory_limit: u64,
+	) -> AppResult<Self> {
+		let mut store = Self::new(memory_limit);
+		for (image, delay) in frames {
+			store.push(image, delay)?;
+		}
+		Ok(store)
+	}
+
+	/**
+	 * Add a frame to the store.
+	 *
+	 * @param  image
+	 * @param  delay
+	 * @return Result
+	 */
+	pub fn push(&mut self, image: Image, delay: Duration) -> AppResult<()> {
+		if let Some(sender) = &self.sender {
+			let geometry = image.geometry;
+			sender.send((image, delay)).map_err(|_| {
+				AppError::FrameError(String::from("Failed to send the frame"))
+			})?;
+			self.frames.push((StoredFrame::Sent(geometry), delay));
+			return Ok(());
+		}
+		let size =
+			u64::from(image.geometry.width) * u64::from(image.geometry.height) * 4;
+		if self.file.is_none() && self.memory_usage + size <= self.memory_limit {
+			self.memory_usage += size;
+			self.frames.push((StoredFrame::Memory(image), delay));
+			return Ok(());
+		}
+		let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+		encoder.write_all(&image.get_raw_data())?;
+		let data = encoder.finish()?;
+		let offset = self.file_size;
+		self.get_file()?.write_all(&data)?;
+		self.file_size += data.len() as u64;
+		self.frames.push((
+			StoredFrame::Disk {
+				offset,
+				size: data.len() as u64,
+				alpha_channel: image.has_alpha(),
+				geometry: image.geometry,
+			},
+			delay,
+		));
+		Ok(())
+	}
+
+	/**
+	 * Get the temporary file, create it if it does not exist.
+	 *
+	 * @return File (Result)
+	 */
+	fn get_file(&mut self) -> AppResult<&File> {
+		if self.file.is_none() {
+			let path = env::temp_dir().join(format!(
+				"menyoki-{}-{}.frames",
+				process::id(),
+				FILE_COUNT.fetch_add(1, Ordering::SeqCst)
+			));
+			debug!("Saving the frames to {:?}", path);
+			let file = OpenOptions::new()
+				.read(true)
+				.append(true)
+				.create_new(true)
+				.open(&path)?;
+			self.file = Some((path, file));
+		}
+		self.file
+			.as_ref()
+			.map(|(_, file)| file)
+			.ok_or_else(|| AppError::FrameError(String::from("No frame file")))
+	}
+
+	/**
+	 * Get a frame from the store.
+	 *
+	 * @param  index
+	 * @return Image (Result)
+	 */
+	pub fn get(&self, index: usize) -> AppResult<Image> {
+		match self.frames.get(index).map(|(frame, _)| frame) {
+			Some(StoredFrame::Memory(image)) => Ok(image.clone()),
+			Some(StoredFrame::Disk {
+				offset,
+				size,
+				alpha_channel,
+				geometry,
+			}) => {
+				let mut file =
+					self.file.as_ref().map(|(_, file)| file).ok_or_else(|| {
+						AppError::FrameError(String::from("No frame file"))
+					})?;
+				file.seek(SeekFrom::Start(*offset))?;
+				let mut data = Vec::with_capacity(
+					(geometry.width * geometry.height * 4) as usize,
+				);
+				DeflateDecoder::new(file.take(*size)).read_to_end(&mut data)?;
+				Ok(Image::from_raw_data(&data, *alpha_channel, *geometry))
+			}
+			Some(StoredFrame::Sent(_)) => Err(AppError::FrameError(format!(
+				"Frame is already sent: {index}"
+			))),
+			None => Err(AppError::FrameError(format!("Frame not found: {index}"))),
+		}
+	}
+
+	/**
+	 * Get the delay of a frame.
+	 *
+	 * @param  index
+	 * @return Duration
+	 */
+	pub fn get_delay(&self, index: usize) -> Duration {
+		self.frames
+			.get(index)
+			.map(|(_, delay)| *delay)
+			.unwrap_or_default()
+	}
+
+	/**
+	 * Get an iterator over the frames.
+	 *
+	 * @return Iterator
+	 */
+	pub fn iter(&self) -> impl Iterator<Item = AppResult<Image>> + '_ {
+		(0..self.len()).map(|i| self.get(i))
+	}
+
+	/**
+	 * Create a new store with the frames that are updated by the given function.
+	 *
+	 * @param  f
+	 * @return FrameStore (Result)
+	 */
+	pub fn map<F: Fn(Image) -> Image>(&self, f: F) -> AppResult<Self> {
+		let mut store = Self::new(self.memory_limit);
+		for (i, image) in self.iter().enumerate() {
+			store.push(f(image?), self.get_delay(i))?;
+		}
+		Ok(store)
+	}
+
+	/**
+	 * Create a copy of the store.
+	 *
+	 * @return FrameStore (Result)
+	 */
+	#[cfg(test)]
+	pub fn try_clone(&self) -> AppResult<Self> {
+		self.map(|image| image)
+	}
+
+	/**
+	 * Get the geometry of the first frame.
+	 *
+	 * @return Geometry (Option)
+	 */
+	pub fn get_geometry(&self) -> Option<Geometry> {
+		self.frames.first().map(|(frame, _)| frame.get_geometry())
+	}
+
+	/**
+	 * Get the number of frames.
+	 *
+	 * @return usize
+	 */
+	pub fn len(&self) -> usize {
+		self.frames.len()
+	}
+
+	/* Remove the frames and the temporary file. */
+	pub fn clear(&mut self) {
+		self.frames.clear();
+		self.sender = None;
+		self.memory_usage = 0;
+		self.file_size = 0;
+		if let Some((path, file)) = self.file.take() {
+			drop(file);
+			if let Err(e) = fs::remove_file(&path) {
+				warn!("Failed to remove {:?}: {}", path, e);
+			}
+		}
+	}
+}
+
+/* Remove the temporary file on drop */
+impl Drop for FrameStore {
+	fn drop(&mut self) {
+		self.clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::{ExtendedColorType, Rgba};
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_frame_store() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 2, 1);
+		let images = (0..4)
+			.map(|v| {
+				(
+					Image::new(
+						vec![Rgba::from([v, v, v, 255]), Rgba::from([0, 0, 0, 0])],
+						v % 2 == 0,
+						geometry,
+					),
+					Duration::from_millis(u64::from(v) * 10),
+				)
+			})
+			.collect::<Vec<TimedFrame>>();
+		let mut store = FrameStore::from_frames(images.clone(), 16)?;
+		assert_eq!(4, store.len());
+		assert_eq!(Some(geometry), store.get_geometry());
+		let path = store.file.as_ref().map(|(path, _)| path.clone()).unwrap();
+		assert!(path.exists());
+		for ((image, _), stored) in images.iter().zip(store.iter()) {
+			assert_eq!(
+				image.get_data(ExtendedColorType::Rgba8),
+				stored?.get_data(ExtendedColorType::Rgba8)
+			);
+		}
+		assert_eq!(0, store.get(2)?.get_data(ExtendedColorType::Rgba8)[7]);
+		assert!(store.get(4).is_err());
+		assert_eq!(Duration::from_millis(30), store.get_delay(3));
+		assert_eq!(Duration::ZERO, store.get_delay(4));
+		let cropped = store.map(|image| image.crop(Geometry::new(1, 0, 1, 1)))?;
+		assert_eq!(Some(Geometry::new(1, 0, 1, 1)), cropped.get_geometry());
+		assert_eq!(Duration::from_millis(20), cropped.get_delay(2));
+		assert!(cropped.file.is_none());
+		assert_eq!(4, store.try_clone()?.len());
+		store.clear();
+		assert_eq!(0, store.len());
+		assert!(!path.exists());
+		let mut store = FrameStore::new(0);
+		store.push(
+			Image::new(
+				vec![Rgba::from([0, 0, 255, 255]); 64 * 64],
+				false,
+				Geometry::new(0, 0, 64, 64),
+			),
+			Duration::ZERO,
+		)?;
+		assert!(store.file_size < 64 * 64 * 4);
+		assert_eq!(
+			vec![0, 0, 255, 255],
+			store.get(0)?.get_data(ExtendedColorType::Rgba8)[..4]
+		);
+		let store = FrameStore::from_frames(images.clone(), 0)?;
+		let path = store.file.as_ref().map(|(path, _)| path.clone()).unwrap();
+		drop(store);
+		assert!(!path.exists());
+		let (sender, receiver) = mpsc::sync_channel(STREAM_BUFFER);
+		let mut store = FrameStore::with_sender(sender);
+		store.push(images[1].0.clone(), images[1].1)?;
+		assert_eq!(1, store.len());
+		assert_eq!(Some(geometry), store.get_geometry());
+		assert!(store.get(0).is_err());
+		assert_eq!(
+			Some((geometry, Duration::from_millis(10))),
+			receiver
+				.recv()
+				.ok()
+				.map(|(image, delay)| (image.geometry, delay))
+		);
+		store.clear();
+		assert!(receiver.recv().is_err());
+		Ok(())
+	}
+}
@@ -1,43 +1,49 @@
 use crate::anim::settings::AnimSettings;
-use crate::app::AppResult;
+use crate::anim::store::FrameStore;
+use crate::anim::TimedFrame;
+use crate::app::{AppError, AppResult};
 use crate::image::geometry::Geometry;
-use crate::image::Image;
 use crate::util::state::InputState;
 use image::ExtendedColorType;
-use png::{BitDepth, ColorType, Encoder, FilterType};
-use std::io::{self, Write};
+use png::{BitDepth, ColorType, Encoder, FilterType, Writer};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+/* Type of the animation control chunk */
+const ACTL_CHUNK: &[u8] = b"acTL";
+/* Type of the first image data chunk */
+const IDAT_CHUNK: &[u8] = b"IDAT";
 
 /* APNG encoder and settings */
 pub struct ApngEncoder<'a, Output: Write> {
 	encoder: Encoder<'a, Output>,
-	settings: &'a AnimSettings,
 }
 
 impl<'a, Output: Write> ApngEncoder<'a, Output> {
 	/**
 	 * Create a new ApngEncoder object.
 	 *
-	 * @param  frame_count
+	 * @param  frame_count (Option)
 	 * @param  geometry
 	 * @param  output
 	 * @param  settings
 	 * @return ApngEncoder (Result)
 	 */
 	pub fn new(
-		frame_count: u32,
+		frame_count: Option<u32>,
 		geometry: Geometry,
 		output: Output,
 		settings: &'a AnimSettings,
 	) -> AppResult<Self> {
 		let mut encoder = Encoder::new(output, geometry.width, geometry.height);
 		encoder.set_animated(
-			frame_count,
+			frame_count.unwrap_or(u32::MAX),
 			settings.repeat.try_into().unwrap_or_default(),
 		)?;
 		encoder.set_color(ColorType::Rgba);
 		encoder.set_depth(BitDepth::Eight);
 		encoder.set_filter(FilterType::NoFilter);
-		Ok(Self { encoder, settings })
+		Ok(Self { encoder })
 	}
 
 	/**
@@ -49,12 +55,12 @@ impl<'a, Output: Write> ApngEncoder<'a, Output> {
 	 */
 	pub fn save(
 		self,
-		images: Vec<Image>,
+		images: FrameStore,
 		input_state: Option<&'static InputState>,
 	) -> AppResult<()> {
 		let mut writer = self.encoder.write_header()?;
-		writer.set_frame_delay(1, self.settings.fps.try_into().unwrap_or(1))?;
-		for (i, image) in images.iter().enumerate() {
+		let mut time = Duration::ZERO;
+		for i in 0..images.len() {
 			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
 			info!("Saving... ({:.1}%)\r", percentage);
 			debug!(
@@ -68,36 +74,161 @@ impl<'a, Output: Write> ApngEncoder<'a, Output> {
 				if state.check_cancel_keys() {
 					info!("\n");
 					warn!("User interrupt detected.");
-					panic!("Failed to write the frames")
+					return Err(AppError::FrameError(String::from(
+						"Failed to write the frames",
+					)));
 				}
 			}
+			let image = images.get(i)?;
+			set_frame_delay(&mut writer, &mut time, images.get_delay(i))?;
 			writer.write_image_data(&image.get_data(ExtendedColorType::Rgba8))?;
 		}
 		info!("\n");
 		Ok(())
 	}
+
+	/**
+	 * Encode images as frame while they are received.
+	 *
+	 * @param  frames
+	 * @return u32 (Result)
+	 */
+	pub fn stream<Frames: Iterator<Item = TimedFrame>>(
+		self,
+		frames: Frames,
+	) -> AppResult<u32> {
+		let mut writer = self.encoder.write_header()?;
+		let mut time = Duration::ZERO;
+		let mut frame_count = 0;
+		for (image, delay) in frames {
+			set_frame_delay(&mut writer, &mut time, delay)?;
+			writer.write_image_data(&image.get_data(ExtendedColorType::Rgba8))?;
+			frame_count += 1;
+		}
+		writer.finish()?;
+		Ok(frame_count)
+	}
+}
+
+/**
+ * Set the delay of the next frame with respect to the elapsed time.
+ *
+ * @param  writer
+ * @param  time
+ * @param  delay
+ * @return Result
+ */
+fn set_frame_delay<Output: Write>(
+	writer: &mut Writer<Output>,
+	time: &mut Duration,
+	delay: Duration,
+) -> AppResult<()> {
+	let start = time.as_millis();
+	*time += delay;
+	writer.set_frame_delay(
+		u16::try_from(time.as_millis() - start).unwrap_or(u16::MAX),
+		1000,
+	)?;
+	Ok(())
+}
+
+/**
+ * Update the frame count in the animation control chunk of the APNG file.
+ *
+ * @param  output
+ * @param  frame_count
+ * @return Result
+ */
+pub fn set_frame_count<Output: Read + Write + Seek>(
+	output: &mut Output,
+	frame_count: u32,
+) -> AppResult<()> {
+	let mut position = output.seek(SeekFrom::Start(8))?;
+	loop {
+		let mut header = [0; 8];
+		output.read_exact(&mut header)?;
+		let length =
+			u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+		match &header[4..] {
+			ACTL_CHUNK => {
+				let mut data = [0; 8];
+				output.read_exact(&mut data)?;
+				data[..4].copy_from_slice(&frame_count.to_be_bytes());
+				let mut hasher = crc32fast::Hasher::new();
+				hasher.update(ACTL_CHUNK);
+				hasher.update(&data);
+				output.seek(SeekFrom::Start(position + 8))?;
+				output.write_all(&data)?;
+				output.write_all(&hasher.finalize().to_be_bytes())?;
+				output.seek(SeekFrom::End(0))?;
+				return Ok(());
+			}
+			IDAT_CHUNK => {
+				return Err(AppError::FrameError(String::from(
+					"Animation control chunk not found",
+				)))
+			}
+			_ => {
+				position = output.seek(SeekFrom::Current(i64::from(length) + 4))?;
+			}
+		}
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use image::Rgba;
+	use crate::image::Image;
+	use image::codecs::png::PngDecoder;
+	use image::{AnimationDecoder, Rgba};
+	use std::io::Cursor;
 	#[test]
 	fn test_apng_encoder() -> AppResult<()> {
 		let geometry = Geometry::new(0, 0, 1, 2);
 		let data = vec![Rgba::from([128, 128, 128, 0]), Rgba::from([16, 16, 16, 0])];
 		let images = vec![
-			Image::new(data.clone(), false, geometry),
-			Image::new(data.into_iter().rev().collect(), false, geometry),
+			(
+				Image::new(data.clone(), false, geometry),
+				Duration::from_micros(2500),
+			),
+			(
+				Image::new(data.into_iter().rev().collect(), false, geometry),
+				Duration::from_millis(40),
+			),
 		];
 		let mut output = Vec::new();
 		ApngEncoder::new(
-			images.len().try_into().unwrap(),
+			images.len().try_into().ok(),
 			geometry,
 			&mut output,
 			&AnimSettings::default(),
 		)?
-		.save(images, None)?;
+		.save(
+			FrameStore::from_frames(
+				images.clone(),
+				AnimSettings::default().memory_limit,
+			)?,
+			None,
+		)?;
+		let mut stream = Cursor::new(Vec::new());
+		let frame_count =
+			ApngEncoder::new(None, geometry, &mut stream, &AnimSettings::default())?
+				.stream(images.into_iter())?;
+		assert_eq!(2, frame_count);
+		set_frame_count(&mut stream, frame_count)?;
+		assert_eq!(output, stream.get_ref().clone());
+		let frames = PngDecoder::new(Cursor::new(stream.into_inner()))?
+			.apng()
+			.into_frames()
+			.collect_frames()?;
+		assert_eq!(
+			vec![(2, 1), (40, 1)],
+			frames
+				.iter()
+				.map(|frame| frame.delay().numer_denom_ms())
+				.collect::<Vec<(u32, u32)>>()
+		);
+		assert!(set_frame_count(&mut Cursor::new(output[..33].to_vec()), 1).is_err());
 		output.truncate(6);
 		assert_eq!(vec![0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a], output);
 		Ok(())
@@ -1,18 +1,26 @@
 use crate::anim::decoder::AnimDecoder;
-use crate::anim::Frames;
-use crate::apng::ApngEncoder;
+use crate::anim::store::{FrameStore, STREAM_BUFFER};
+use crate::anim::TimedFrame;
+use crate::apng::{self, ApngEncoder};
 use crate::args::Args;
+#[cfg(feature = "av1")]
+use crate::av1::{self, Av1Encoder};
+use crate::avi::AviEncoder;
 use crate::file::format::FileFormat;
 use crate::file::File as FileUtil;
 use crate::gif::encoder::{Encoder, EncoderConfig};
 #[cfg(feature = "ski")]
 use crate::gif::ski::GifskiEncoder;
 use crate::gif::GifEncoder;
+use crate::image::geometry::Geometry;
 use crate::image::Image;
+use crate::record::sequence::FrameSequence;
+use crate::record::settings::{RecordMonitor, RecordSequence, RecordTimelapse};
 use crate::record::Recorder;
 use crate::settings::AppSettings;
+use crate::util::state::InputState;
 use crate::view::ImageViewer;
-use crate::window::Capture;
+use crate::window::{Access, Capture};
 use bytesize::ByteSize;
 use image::codecs::bmp::BmpEncoder;
 use image::codecs::farbfeld::FarbfeldEncoder;
@@ -34,10 +42,14 @@ use image::{
 	AnimationDecoder, ColorType, ExtendedColorType, ImageEncoder, ImageFormat,
 };
 use std::fmt::Debug;
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Cursor, Read, Seek, Write};
-use std::path::Path;
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::sync::{mpsc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 use thiserror::Error as ThisError;
 
 /* Custom error implementation */
@@ -64,10 +76,18 @@ pub enum AppError {
 	FrameError(String),
 	#[error("Command error: `{0}`")]
 	CommandError(String),
+	#[error("Regex error: `{0}`")]
+	Regex(#[from] regex::Error),
+	#[cfg(feature = "av1")]
+	#[error("AV1 config error: `{0}`")]
+	Av1Config(#[from] rav1e::InvalidConfig),
+	#[cfg(feature = "av1")]
+	#[error("AV1 encoding error: `{0}`")]
+	Av1Encoding(#[from] rav1e::EncoderStatus),
 }
 
 /* Application output and result types */
-pub type AppOutput = (Option<Image>, Option<Frames>);
+pub type AppOutput = (Option<Image>, Option<FrameStore>);
 pub type AppResult<T> = std::result::Result<T, AppError>;
 
 /* Application and main functionalities */
@@ -75,6 +95,7 @@ pub type AppResult<T> = std::result::Result<T, AppError>;
 pub struct App<'a, Window> {
 	window: Option<Window>,
 	settings: &'a AppSettings<'a>,
+	process: Option<&'a Mutex<Option<Child>>>,
 }
 
 impl<'a, Window> App<'a, Window>
@@ -89,7 +110,20 @@ where
 	 * @return App
 	 */
 	pub fn new(window: Option<Window>, settings: &'a AppSettings<'a>) -> Self {
-		Self { window, settings }
+		Self {
+			window,
+			settings,
+			process: None,
+		}
+	}
+
+	/**
+	 * Wait for the given process instead of running the command.
+	 *
+	 * @param  process
+	 */
+	pub fn set_process(&mut self, process: &'a Mutex<Option<Child>>) {
+		self.process = Some(process);
 	}
 
 	/**
@@ -119,15 +153,33 @@ where
 		} else if self.settings.args.is_present("view") {
 			debug!("Viewing the image... ({:?})", self.settings.view.file);
 			self.view_image()?;
+		} else if self.settings.record.flag.monitor == Some(RecordMonitor::All) {
+			self.save_monitors()?;
+		} else if let Some(timelapse) = self.settings.record.timelapse {
+			self.capture_timelapse(timelapse)?;
+		} else if let (Some(sequence), true) =
+			(self.settings.record.sequence, self.can_stream())
+		{
+			let frame_count = self.record_sequence(sequence)?;
+			info!(
+				"{} frames saved to: {:?} ({})",
+				frame_count,
+				sequence.dir,
+				sequence.format.as_extension().to_uppercase()
+			);
 		} else if self.settings.save.file.path.to_str() == Some("-") {
 			let mut buffer = Cursor::new(Vec::new());
 			self.save_output(self.get_app_output()?, &mut buffer)?;
 			io::stdout().write_all(&buffer.into_inner())?;
 		} else {
-			self.save_output(
-				self.get_app_output()?,
-				File::create(&self.settings.save.file.path)?,
-			)?;
+			if self.can_stream() {
+				self.stream_anim()?;
+			} else {
+				self.save_output(
+					self.get_app_output()?,
+					File::create(&self.settings.save.file.path)?,
+				)?;
+			}
 			info!(
 				"{} saved to: {:?} ({})",
 				self.settings.save.file.format.as_extension().to_uppercase(),
@@ -138,6 +190,120 @@ where
 		Ok(())
 	}
 
+	/**
+	 * Start a capture session that saves a screenshot of each
+	 * selected window until the selection is cancelled.
+	 *
+	 * @param  system
+	 * @param  settings
+	 * @return Result
+	 */
+	pub fn start_session<System: Access<'a, Window>>(
+		system: &mut System,
+		settings: &'a AppSettings<'a>,
+	) -> AppResult<()> {
+		let interval = Duration::from_millis(settings.record.time.interval);
+		let (mut index, mut shot_count) = (0, 0);
+		loop {
+			if let Some(input_state) = settings.input_state {
+				while input_state.check_action() {
+					thread::sleep(interval);
+				}
+			}
+			info!("Select a window to capture... (shot #{})", shot_count + 1);
+			let window = match system.get_window() {
+				Ok(window) => window,
+				Err(e) => {
+					warn!("{}", e);
+					break;
+				}
+			};
+			let path = Self::get_session_path(&settings.save.file.path, &mut index);
+			App::new(Some(window), settings).save_shot(&path)?;
+			shot_count += 1;
+		}
+		info!("Session ended. ({} screenshots saved)", shot_count);
+		Ok(())
+	}
+
+	/**
+	 * Get the next unused file path for the session.
+	 *
+	 * @param  path
+	 * @param  index
+	 * @return PathBuf
+	 */
+	fn get_session_path(path: &Path, index: &mut usize) -> PathBuf {
+		loop {
+			let session_path =
+				FileUtil::get_path_with_suffix(path, &format!("{index:04}"));
+			*index += 1;
+			if !session_path.exists() {
+				return session_path;
+			}
+		}
+	}
+
+	/**
+	 * Capture the image of window and save it to the given path.
+	 *
+	 * @param  path
+	 * @return Result
+	 */
+	fn save_shot(self, path: &Path) -> AppResult<()> {
+		let image = self.capture()?;
+		self.save_output((Some(image), None), File::create(path)?)?;
+		info!(
+			"{} saved to: {:?} ({})",
+			self.settings.save.file.format.as_extension().to_uppercase(),
+			path,
+			ByteSize(fs::metadata(path)?.len())
+		);
+		Ok(())
+	}
+
+	/**
+	 * Save the output of each monitor to a separate file.
+	 *
+	 * @return Result
+	 */
+	fn save_monitors(&self) -> AppResult<()> {
+		let monitors = self
+			.window
+			.map(|window| window.get_monitors())
+			.unwrap_or_default();
+		let (image, frames) = self.get_app_output()?;
+		for monitor in monitors {
+			let path = FileUtil::get_path_with_suffix(
+				&self.settings.save.file.path,
+				&monitor.name,
+			);
+			debug!(
+				"Saving the output of {} ({:?})",
+				monitor.name, monitor.geometry
+			);
+			self.save_output(
+				(
+					image.as_ref().map(|image| image.crop(monitor.geometry)),
+					frames
+						.as_ref()
+						.map(|images| {
+							images.map(|image| image.crop(monitor.geometry))
+						})
+						.transpose()?,
+				),
+				File::create(&path)?,
+			)?;
+			info!(
+				"{} saved to: {:?} ({})",
+				self.settings.save.file.format.as_extension().to_uppercase(),
+				path,
+				ByteSize(fs::metadata(&path)?.len())
+			);
+		}
+		Ok(())
+	}
+
 	/**
 	 * Get the application output.
 	 *
@@ -173,9 +339,9 @@ where
 	/**
 	 * Get the frames to save.
 	 *
-	 * @return Frames (Result)
+	 * @return FrameStore (Result)
 	 */
-	fn get_frames(self) -> AppResult<Frames> {
+	fn get_frames(self) -> AppResult<FrameStore> {
 		if self.settings.args.is_present("edit") {
 			info!("Reading frames from {:?}...", self.settings.edit.path);
 			self.edit_anim(
@@ -187,17 +353,144 @@ where
 				"Making an animation from {} frames...",
 				self.settings.anim.frames.len()
 			);
-			let mut images = Vec::new();
+			let mut images = FrameStore::new(self.settings.anim.memory_limit);
 			for path in &self.settings.anim.frames {
 				debug!("Reading a frame from {:?}   \r", path);
 				io::stdout().flush()?;
-				images.push(self.edit_image(path)?);
+				images.push(
+					self.edit_image(path)?,
+					self.settings.anim.get_frame_delay(),
+				)?;
 			}
 			debug!("\n");
-			Ok((images, self.settings.anim.fps))
+			Ok(images)
+		} else {
+			self.record(None)
+		}
+	}
+
+	/**
+	 * Check if the frames can be encoded while recording.
+	 *
+	 * @return bool
+	 */
+	fn can_stream(&self) -> bool {
+		self.settings.save.file.format.is_animation()
+			&& !self.settings.args.is_present("edit")
+			&& !self.settings.args.is_present("make")
+	}
+
+	/**
+	 * Record the frames and encode them on a worker thread while recording.
+	 *
+	 * @return Result
+	 */
+	fn stream_anim(self) -> AppResult<()> {
+		let path = &self.settings.save.file.path;
+		let output = OpenOptions::new()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.open(path)?;
+		let (sender, receiver) = mpsc::sync_channel(STREAM_BUFFER);
+		let frame_count = thread::scope(|scope| {
+			let encoder = scope.spawn(move || self.encode_stream(receiver, output));
+			let frame_count = self.record(Some(sender)).map(|frames| frames.len());
+			info!("Encoding the remaining frames...");
+			encoder
+				.join()
+				.expect("Failed to join the encoder thread.")
+				.and(frame_count)
+		});
+		if frame_count.as_ref().map_or(true, |count| *count == 0) {
+			fs::remove_file(path)?;
+		}
+		if frame_count? == 0 {
+			Err(AppError::FrameError(String::from(
+				"No frames found to save",
+			)))
+		} else {
+			Ok(())
+		}
+	}
+
+	/**
+	 * Record the frames and write them to a directory as an image sequence.
+	 *
+	 * @param  sequence
+	 * @return usize (Result)
+	 */
+	fn record_sequence(self, sequence: RecordSequence) -> AppResult<usize> {
+		let mut frames =
+			FrameSequence::new(Path::new(sequence.dir), sequence.format)?;
+		let (sender, receiver) = mpsc::sync_channel::<TimedFrame>(STREAM_BUFFER);
+		let frame_count = thread::scope(|scope| {
+			let writer = scope.spawn(move || -> AppResult<FrameSequence> {
+				for (image, delay) in receiver {
+					frames.write(&image, delay)?;
+				}
+				Ok(frames)
+			});
+			let record = self.record(Some(sender));
+			let frames = writer.join().expect("Failed to join the writer thread.");
+			record.and(frames).map(|frames| {
+				let frame_count = frames.len();
+				frames.clean();
+				frame_count
+			})
+		})?;
+		if frame_count == 0 {
+			Err(AppError::FrameError(String::from(
+				"No frames found to save",
+			)))
 		} else {
-			Ok((self.record()?, self.settings.anim.fps))
+			Ok(frame_count)
+		}
+	}
+
+	/**
+	 * Encode the received frames to the output file.
+	 *
+	 * @param  receiver
+	 * @param  output
+	 * @return Result
+	 */
+	fn encode_stream(
+		self,
+		receiver: mpsc::Receiver<TimedFrame>,
+		mut output: File,
+	) -> AppResult<()> {
+		let first_frame = match receiver.recv() {
+			Ok(frame) => frame,
+			Err(_) => return Ok(()),
+		};
+		let geometry = first_frame.0.geometry;
+		let frames = iter::once(first_frame).chain(receiver);
+		if self.settings.save.file.format == FileFormat::Av1 {
+			return self.stream_av1(frames, geometry, output);
+		}
+		if self.settings.save.file.format == FileFormat::Avi {
+			return AviEncoder::new(
+				geometry,
+				output,
+				self.settings.anim.fps,
+				&self.settings.jpg,
+			)?
+			.stream(frames);
+		}
+		if self.settings.save.file.format == FileFormat::Apng {
+			let frame_count =
+				ApngEncoder::new(None, geometry, &mut output, &self.settings.anim)?
+					.stream(frames)?;
+			return apng::set_frame_count(&mut output, frame_count);
+		}
+		let config = EncoderConfig::new(geometry, output, &self.settings.anim);
+		#[cfg(feature = "ski")]
+		if self.settings.anim.gifski.0 {
+			return GifskiEncoder::new(config)?.stream(frames);
 		}
+		GifEncoder::new(config)?.stream(frames)
 	}
 
 	/**
@@ -215,15 +508,7 @@ where
 				info!("Capturing an image...");
 				window.get_image()
 			});
-			self.settings
-				.record
-				.get_command()
-				.ok_or_else(|| {
-					AppError::CommandError(String::from(
-						"No command specified to run",
-					))
-				})?
-				.execute()?;
+			self.run_command()?;
 			image_thread
 				.join()
 				.expect("Failed to join the image thread.")
@@ -238,32 +523,30 @@ where
 	/**
 	 * Start recording the frames.
 	 *
-	 * @return Vector of Image (Result)
+	 * @param  sender (Option)
+	 * @return FrameStore (Result)
 	 */
-	fn record(self) -> AppResult<Vec<Image>> {
+	fn record(
+		self,
+		sender: Option<mpsc::SyncSender<TimedFrame>>,
+	) -> AppResult<FrameStore> {
 		let mut recorder = Recorder::new(
 			self.window.ok_or_else(|| {
 				AppError::WsError(String::from("Failed to get the window"))
 			})?,
 			self.settings.anim.fps,
 			self.settings.anim.gifski.0,
+			self.settings.anim.memory_limit,
+			self.settings.anim.collapse,
 			self.settings.record,
 		);
+		if let Some(sender) = sender {
+			recorder.set_sender(sender);
+		}
 		if self.settings.record.command.is_some() {
-			let record = recorder.record_async();
-			self.settings
-				.record
-				.get_command()
-				.ok_or_else(|| {
-					AppError::CommandError(String::from(
-						"No command specified to run",
-					))
-				})?
-				.execute()?;
-			Ok(match record.get() {
-				Some(frames) => frames.expect("Failed to retrieve the frames."),
-				None => Vec::new(),
-			})
+			let record = recorder.record_async(self.settings.input_state);
+			self.run_command()?;
+			record.get().expect("Failed to retrieve the frames.")
 		} else {
 			Ok(recorder.record_sync(
 				if self.settings.record.flag.action_keys.is_some() {
@@ -275,6 +558,162 @@ where
 		}
 	}
 
+	/**
+	 * Run the command (if it is not started) and wait for it to exit.
+	 *
+	 * @return Result
+	 */
+	fn run_command(&self) -> AppResult<()> {
+		let process = self.process.and_then(|process| process.lock().ok()?.take());
+		match process {
+			Some(mut child) => {
+				child.wait()?;
+			}
+			None => self
+				.settings
+				.record
+				.get_command()
+				.ok_or_else(|| {
+					AppError::CommandError(String::from(
+						"No command specified to run",
+					))
+				})?
+				.execute()?,
+		}
+		Ok(())
+	}
+
+	/**
+	 * Capture images periodically and save them as numbered files
+	 * or as an animation.
+	 *
+	 * @param  timelapse
+	 * @return Result
+	 */
+	fn capture_timelapse(self, timelapse: RecordTimelapse) -> AppResult<()> {
+		let path = &self.settings.save.file.path;
+		let (image_count, path) = match timelapse.fps {
+			Some(fps) => {
+				if !self.settings.save.file.format.is_animation() {
+					return Err(AppError::FrameError(format!(
+						"Periodic captures cannot be animated as {}",
+						self.settings.save.file.format.as_extension().to_uppercase()
+					)));
+				}
+				let output = File::create(path)?;
+				let delay = Duration::from_secs_f64(1. / f64::from(fps));
+				let (sender, receiver) = mpsc::sync_channel(STREAM_BUFFER);
+				let image_count = thread::scope(|scope| {
+					let encoder =
+						scope.spawn(move || self.encode_stream(receiver, output));
+					let image_count = self.run_timelapse(timelapse, |image| {
+						sender.send((image, delay)).map_err(|_| {
+							AppError::FrameError(String::from(
+								"Failed to send the frame",
+							))
+						})
+					});
+					drop(sender);
+					encoder
+						.join()
+						.expect("Failed to join the encoder thread.")
+						.and(image_count)
+				});
+				if image_count.as_ref().map_or(true, |count| *count == 0) {
+					fs::remove_file(path)?;
+				}
+				(image_count?, path.clone())
+			}
+			None => {
+				let mut index = 0;
+				let image_count = self.run_timelapse(timelapse, |image| {
+					let path =
+						FileUtil::get_path_with_suffix(path, &format!("{index:04}"));
+					index += 1;
+					debug!("Saving to {:?}", path);
+					self.save_output((Some(image), None), File::create(path)?)
+				})?;
+				(image_count, path.clone())
+			}
+		};
+		if let Some(window) = self.window {
+			window.release();
+		}
+		info!("{} images are captured. ({:?})", image_count, path);
+		Ok(())
+	}
+
+	/**
+	 * Capture the images periodically and pass them to the given function.
+	 *
+	 * @param  timelapse
+	 * @param  save
+	 * @return usize (Result)
+	 */
+	fn run_timelapse<F: FnMut(Image) -> AppResult<()>>(
+		self,
+		timelapse: RecordTimelapse,
+		mut save: F,
+	) -> AppResult<usize> {
+		let window = self.window.ok_or_else(|| {
+			AppError::WsError(String::from("Failed to get the window"))
+		})?;
+		let input_state = if self.settings.record.flag.action_keys.is_some() {
+			self.settings.input_state
+		} else {
+			None
+		};
+		window.show_countdown();
+		info!("Capturing an image every {:?}...", timelapse.interval);
+		let start_time = Instant::now();
+		let mut last_image: Option<Image> = None;
+		let mut image_count = 0;
+		for i in 0..timelapse.count.unwrap_or(u32::MAX) {
+			if !self.wait_until(start_time + timelapse.interval * i, input_state) {
+				break;
+			}
+			let image = window.get_image().ok_or_else(|| {
+				AppError::WsError(String::from("Failed to get image"))
+			})?;
+			if let (Some(threshold), Some(last_image)) =
+				(timelapse.threshold, &last_image)
+			{
+				if last_image.is_similar(&image, threshold) {
+					debug!("Skipping the image #{}", i + 1);
+					continue;
+				}
+			}
+			if timelapse.threshold.is_some() {
+				last_image = Some(image.clone());
+			}
+			save(image)?;
+			image_count += 1;
+			debug!("Images: {} ({} captured)", image_count, i + 1);
+		}
+		Ok(image_count)
+	}
+
+	/**
+	 * Wait until the given time, return false if the action
+	 * or cancel keys are pressed.
+	 *
+	 * @param  time
+	 * @param  input_state (Option)
+	 * @return bool
+	 */
+	fn wait_until(self, time: Instant, input_state: Option<&InputState>) -> bool {
+		let interval = Duration::from_millis(self.settings.record.time.interval);
+		while let Some(remaining) = time.checked_duration_since(Instant::now()) {
+			if input_state.is_some_and(|state| {
+				state.check_action() || state.check_cancel_keys()
+			}) {
+				return false;
+			}
+			thread::sleep(remaining.min(interval));
+		}
+		true
+	}
+
 	/**
 	 * Edit and return the image.
 	 *
@@ -335,9 +774,13 @@ where
 	 *
 	 * @param  input
 	 * @param  path
-	 * @return Frames (Result)
+	 * @return FrameStore (Result)
 	 */
-	fn edit_anim<Input: Read>(self, input: Input, path: &Path) -> AppResult<Frames> {
+	fn edit_anim<Input: Read>(
+		self,
+		input: Input,
+		path: &Path,
+	) -> AppResult<FrameStore> {
 		let format = Reader::open(path)?.with_guessed_format()?.format();
 		let frames =
 			AnimDecoder::new(self.settings.edit.get_imageops(), &self.settings.anim)
@@ -363,20 +806,19 @@ where
 	 * Split animation into frames.
 	 *
 	 * @param  input
-	 * @return Frames (Result)
+	 * @return Result
 	 */
 	fn split_anim<Input: Read>(self, input: Input) -> AppResult<()> {
-		let (frames, fps) = self.edit_anim(input, &self.settings.split.file)?;
-		debug!("FPS: {}", fps);
+		let frames = self.edit_anim(input, &self.settings.split.file)?;
 		fs::create_dir_all(&self.settings.split.dir)?;
 		for i in 0..frames.len() {
 			let path = FileUtil::get_path_with_extension(
 				self.settings.split.dir.join(format!("frame_{i}",)),
 				&self.settings.save.file.format,
 			);
-			debug!("Saving to {:?}\r", path);
+			debug!("Saving to {:?} ({:?})\r", path, frames.get_delay(i));
 			io::stdout().flush()?;
-			self.save_output((frames.get(i).cloned(), None), File::create(path)?)?;
+			self.save_output((Some(frames.get(i)?), None), File::create(path)?)?;
 		}
 		debug!("\n");
 		Ok(())
@@ -404,6 +846,14 @@ where
 				debug!("{:?}", self.settings.anim);
 				self.save_apng(frames, output)
 			}
+			FileFormat::Av1 => {
+				debug!("{:?}", self.settings.anim);
+				self.save_av1(frames, output)
+			}
+			FileFormat::Avi => {
+				debug!("{:?}", self.settings.jpg);
+				self.save_avi(frames, output)
+			}
 			FileFormat::Png => self.save_image(
 				image,
 				PngEncoder::new_with_quality(
@@ -526,19 +976,16 @@ where
 	#[cfg(feature = "ski")]
 	fn save_gif<Output: Write>(
 		self,
-		frames: Option<Frames>,
+		frames: Option<FrameStore>,
 		output: Output,
 	) -> AppResult<()> {
-		let (images, fps) = frames.ok_or_else(|| {
+		let images = frames.ok_or_else(|| {
 			AppError::FrameError(String::from("Failed to get the frames"))
 		})?;
-		let geometry = images
-			.first()
-			.ok_or_else(|| {
-				AppError::FrameError(String::from("No frames found to save"))
-			})?
-			.geometry;
-		let config = EncoderConfig::new(fps, geometry, output, &self.settings.anim);
+		let geometry = images.get_geometry().ok_or_else(|| {
+			AppError::FrameError(String::from("No frames found to save"))
+		})?;
+		let config = EncoderConfig::new(geometry, output, &self.settings.anim);
 		if self.settings.anim.gifski.0 {
 			GifskiEncoder::new(config)?.save(images, self.settings.input_state)?;
 		} else {
@@ -557,25 +1004,17 @@ where
 	#[cfg(not(feature = "ski"))]
 	fn save_gif<Output: Write>(
 		self,
-		frames: Option<Frames>,
+		frames: Option<FrameStore>,
 		output: Output,
 	) -> AppResult<()> {
-		let (images, fps) = frames.ok_or_else(|| {
+		let images = frames.ok_or_else(|| {
 			AppError::FrameError(String::from("Failed to get the frames"))
 		})?;
-		let geometry = images
-			.first()
-			.ok_or_else(|| {
-				AppError::FrameError(String::from("No frames found to save"))
-			})?
-			.geometry;
-		GifEncoder::new(EncoderConfig::new(
-			fps,
-			geometry,
-			output,
-			&self.settings.anim,
-		))?
-		.save(images, self.settings.input_state)?;
+		let geometry = images.get_geometry().ok_or_else(|| {
+			AppError::FrameError(String::from("No frames found to save"))
+		})?;
+		GifEncoder::new(EncoderConfig::new(geometry, output, &self.settings.anim))?
+			.save(images, self.settings.input_state)?;
 		Ok(())
 	}
 
@@ -588,22 +1027,17 @@ where
 	 */
 	fn save_apng<Output: Write>(
 		self,
-		frames: Option<Frames>,
+		frames: Option<FrameStore>,
 		output: Output,
 	) -> AppResult<()> {
-		let images = frames
-			.ok_or_else(|| {
-				AppError::FrameError(String::from("Failed to get the frames"))
-			})?
-			.0;
-		let geometry = images
-			.first()
-			.ok_or_else(|| {
-				AppError::FrameError(String::from("No frames found to save"))
-			})?
-			.geometry;
+		let images = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		let geometry = images.get_geometry().ok_or_else(|| {
+			AppError::FrameError(String::from("No frames found to save"))
+		})?;
 		ApngEncoder::new(
-			images.len().try_into().unwrap_or_default(),
+			images.len().try_into().ok(),
 			geometry,
 			output,
 			&self.settings.anim,
@@ -611,6 +1045,119 @@ where
 		.save(images, self.settings.input_state)?;
 		Ok(())
 	}
+
+	/**
+	 * Save frames to an AV1 video file. (IVF/WebM)
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @return  Result
+	 */
+	#[cfg(feature = "av1")]
+	fn save_av1<Output: Write + Seek>(
+		self,
+		frames: Option<FrameStore>,
+		output: Output,
+	) -> AppResult<()> {
+		let images = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		let geometry = images.get_geometry().ok_or_else(|| {
+			AppError::FrameError(String::from("No frames found to save"))
+		})?;
+		Av1Encoder::new(
+			geometry,
+			av1::get_muxer(&self.settings.save.file.path, output),
+			&self.settings.anim,
+		)?
+		.save(images, self.settings.input_state)
+	}
+
+	/**
+	 * Save frames to an AV1 video file. (IVF/WebM)
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @return  Result
+	 */
+	#[cfg(not(feature = "av1"))]
+	fn save_av1<Output: Write + Seek>(
+		self,
+		_: Option<FrameStore>,
+		_: Output,
+	) -> AppResult<()> {
+		Err(AppError::FrameError(String::from(
+			"AV1 encoder is not enabled (build with the \"av1\" feature)",
+		)))
+	}
+
+	/**
+	 * Save frames to a Motion JPEG (AVI) file.
+	 *
+	 * @param   frames (Option)
+	 * @param   output
+	 * @return  Result
+	 */
+	fn save_avi<Output: Write + Seek>(
+		self,
+		frames: Option<FrameStore>,
+		output: Output,
+	) -> AppResult<()> {
+		let images = frames.ok_or_else(|| {
+			AppError::FrameError(String::from("Failed to get the frames"))
+		})?;
+		let geometry = images.get_geometry().ok_or_else(|| {
+			AppError::FrameError(String::from("No frames found to save"))
+		})?;
+		AviEncoder::new(
+			geometry,
+			output,
+			self.settings.anim.fps,
+			&self.settings.jpg,
+		)?
+		.save(images, self.settings.input_state)
+	}
+
+	/**
+	 * Encode the received frames to an AV1 video file. (IVF/WebM)
+	 *
+	 * @param   frames
+	 * @param   geometry
+	 * @param   output
+	 * @return  Result
+	 */
+	#[cfg(feature = "av1")]
+	fn stream_av1<Frames: Iterator<Item = TimedFrame>>(
+		self,
+		frames: Frames,
+		geometry: Geometry,
+		output: File,
+	) -> AppResult<()> {
+		Av1Encoder::new(
+			geometry,
+			av1::get_muxer(&self.settings.save.file.path, output),
+			&self.settings.anim,
+		)?
+		.stream(frames)
+	}
+
+	/**
+	 * Encode the received frames to an AV1 video file. (IVF/WebM)
+	 *
+	 * @param   frames
+	 * @param   geometry
+	 * @param   output
+	 * @return  Result
+	 */
+	#[cfg(not(feature = "av1"))]
+	fn stream_av1<Frames: Iterator<Item = TimedFrame>>(
+		self,
+		_: Frames,
+		_: Geometry,
+		output: File,
+	) -> AppResult<()> {
+		self.save_av1(None, output)
+	}
 }
 
 #[cfg(test)]
@@ -651,7 +1198,55 @@ mod tests {
 		}
 		settings.save.file.path = PathBuf::from("test");
 		App::new(Some(window), &settings).start()?;
-		fs::remove_file(settings.save.file.path)?;
+		fs::remove_file(&settings.save.file.path)?;
+		settings.save.file.path = PathBuf::from("test.png");
+		settings.record.flag.monitor = Some(RecordMonitor::All);
+		App::new(Some(window), &settings).start()?;
+		fs::remove_file("test_TEST-1.png")?;
+		settings.record.flag.monitor = None;
+		settings.save.file.path = PathBuf::from("test_timelapse.png");
+		settings.record.timelapse = Some(RecordTimelapse::new(
+			Duration::from_millis(10),
+			Some(3),
+			None,
+			None,
+		));
+		App::new(Some(window), &settings).start()?;
+		for i in 0..3 {
+			fs::remove_file(format!("test_timelapse_{i:04}.png"))?;
+		}
+		settings.record.timelapse = Some(RecordTimelapse::new(
+			Duration::from_millis(10),
+			Some(3),
+			Some(0),
+			Some(10),
+		));
+		assert!(App::new(Some(window), &settings).start().is_err());
+		let format = settings.save.file.format.clone();
+		settings.save.file.format = FileFormat::Gif;
+		settings.save.file.path = PathBuf::from("test_timelapse.gif");
+		App::new(Some(window), &settings).start()?;
+		settings.save.file.format = format;
+		let frames = GifDecoder::new(File::open("test_timelapse.gif")?)?
+			.into_frames()
+			.collect_frames()?;
+		assert_eq!(1, frames.len());
+		assert_eq!((100, 1), frames[0].delay().numer_denom_ms());
+		fs::remove_file("test_timelapse.gif")?;
+		settings.record.timelapse = None;
+		settings.save.file.path = PathBuf::from("test_session.png");
+		let (mut index, session_path) = (0, PathBuf::from("test_session_0000.png"));
+		let app = App::new(Some(window), &settings);
+		app.save_shot(&session_path)?;
+		assert_eq!(
+			PathBuf::from("test_session_0001.png"),
+			App::<TestWindow>::get_session_path(
+				&settings.save.file.path,
+				&mut index
+			)
+		);
+		assert_eq!(2, index);
+		fs::remove_file(session_path)?;
 		Ok(())
 	}
 	#[test]
@@ -664,8 +1259,8 @@ mod tests {
 		settings.anim.cut = (0.1, 0.1);
 		let window = TestWindow::default();
 		let app = App::new(Some(window), &settings);
-		let images = app.get_frames()?.0;
-		app.save_gif(Some((images.clone(), 10)), File::create("test.gif")?)?;
+		let images = app.get_frames()?;
+		app.save_gif(Some(images.try_clone()?), File::create("test.gif")?)?;
 		app.edit_anim(File::open("test.gif")?, Path::new("test.gif"))?;
 		let dir = env::current_dir()?;
 		settings.split.dir = PathBuf::from(dir.to_str().unwrap_or_default());
@@ -674,8 +1269,29 @@ mod tests {
 		let app = App::new(Some(window), &settings);
 		app.split_anim(File::open("test.gif")?)?;
 		fs::remove_file("test.gif")?;
-		app.save_apng(Some((images.clone(), 20)), File::create("test.apng")?)?;
+		app.save_apng(Some(images.try_clone()?), File::create("test.apng")?)?;
 		fs::remove_file("test.apng")?;
+		app.save_avi(Some(images.try_clone()?), File::create("test.avi")?)?;
+		fs::remove_file("test.avi")?;
+		settings.save.file.format = FileFormat::Gif;
+		settings.save.file.path = PathBuf::from("test_stream.gif");
+		App::new(Some(window), &settings).start()?;
+		assert!(fs::metadata("test_stream.gif")?.len() > 0);
+		fs::remove_file("test_stream.gif")?;
+		settings.save.file.format = FileFormat::Apng;
+		settings.save.file.path = PathBuf::from("test_stream.apng");
+		App::new(Some(window), &settings).start()?;
+		let frames = PngDecoder::new(File::open("test_stream.apng")?)?
+			.apng()
+			.into_frames()
+			.collect_frames()?;
+		assert!(!frames.is_empty());
+		fs::remove_file("test_stream.apng")?;
+		settings.save.file.format = FileFormat::Avi;
+		settings.save.file.path = PathBuf::from("test_stream.avi");
+		App::new(Some(window), &settings).start()?;
+		assert!(fs::metadata("test_stream.avi")?.len() > 0);
+		fs::remove_file("test_stream.avi")?;
 		for i in 0..images.len() {
 			let path = PathBuf::from(format!("frame_{i}.png"));
 			if path.exists() {
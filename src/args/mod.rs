@@ -2,6 +2,7 @@ pub mod matches;
 pub mod parser;
 use crate::anim::{AnimFormat, AnimMode};
 use crate::file::format::FileFormat;
+use crate::record::settings::{RecordTimelapse, WindowFilter};
 use clap::{App, AppSettings, Arg, ArgMatches, Shell, SubCommand};
 use std::io::Write;
 use std::str::FromStr;
@@ -35,6 +36,7 @@ pub struct Args<'a, 'b> {
 	edit: App<'a, 'b>,
 	analyze: App<'a, 'b>,
 	view: App<'a, 'b>,
+	list: App<'a, 'b>,
 	misc: App<'a, 'b>,
 }
 
@@ -81,6 +83,7 @@ where
 			edit: Self::get_edit_args(),
 			analyze: Self::get_analyze_args(),
 			view: Self::get_view_args(),
+			list: Self::get_list_args(),
 			misc: Self::get_misc_args(),
 		}
 	}
@@ -144,14 +147,7 @@ where
 			)
 			.subcommand(
 				args.record
-					.subcommand(
-						Self::get_anim_args(AnimMode::Record(AnimFormat::Gif))
-							.subcommand(Self::get_save_args(FileFormat::Gif)),
-					)
-					.subcommand(
-						Self::get_anim_args(AnimMode::Record(AnimFormat::Apng))
-							.subcommand(Self::get_save_args(FileFormat::Apng)),
-					)
+					.subcommands(Self::get_anim_subcommands(AnimMode::Record))
 					.subcommand(Self::get_save_args(FileFormat::Gif)),
 			)
 			.subcommand(Self::get_image_args(args.split, false))
@@ -159,14 +155,7 @@ where
 			.subcommand(Self::get_image_args(args.capture, true))
 			.subcommand(Self::get_image_args(
 				args.edit
-					.subcommand(
-						Self::get_anim_args(AnimMode::Edit(AnimFormat::Gif))
-							.subcommand(Self::get_save_args(FileFormat::Gif)),
-					)
-					.subcommand(
-						Self::get_anim_args(AnimMode::Edit(AnimFormat::Apng))
-							.subcommand(Self::get_save_args(FileFormat::Apng)),
-					),
+					.subcommands(Self::get_anim_subcommands(AnimMode::Edit)),
 				true,
 			))
 			.subcommand(
@@ -174,6 +163,7 @@ where
 					.subcommand(Self::get_save_args(FileFormat::Txt)),
 			)
 			.subcommand(args.view)
+			.subcommand(args.list)
 			.subcommand(args.misc)
 	}
 
@@ -237,6 +227,136 @@ where
 						"Record with the alpha channel"
 					}),
 			)
+			.arg(Arg::with_name("cursor").long("cursor").help(if capture {
+				"Capture the mouse cursor"
+			} else {
+				"Record the mouse cursor"
+			}))
+			.arg(
+				Arg::with_name("composite")
+					.long("composite")
+					.conflicts_with("root")
+					.help(if capture {
+						"Capture the window contents even if obscured"
+					} else {
+						"Record the window contents even if obscured"
+					}),
+			)
+			.arg(
+				Arg::with_name("drag")
+					.long("drag")
+					.conflicts_with_all(&[
+						"focus",
+						"parent",
+						"all-monitors",
+						"window-id",
+						"window-title",
+						"window-class",
+						"pid",
+					])
+					.help(if capture {
+						"Select the area to capture by dragging the mouse"
+					} else {
+						"Select the area to record by dragging the mouse"
+					}),
+			)
+			.arg(
+				Arg::with_name("freeze")
+					.long("freeze")
+					.conflicts_with_all(&[
+						"focus",
+						"parent",
+						"all-monitors",
+						"window-id",
+						"window-title",
+						"window-class",
+						"pid",
+					])
+					.help("Freeze the screen while selecting the area")
+					.hidden(!capture),
+			)
+			.arg(
+				Arg::with_name("session")
+					.long("session")
+					.conflicts_with_all(&[
+						"command",
+						"all-monitors",
+						"window-id",
+						"window-title",
+						"window-class",
+						"pid",
+						"every",
+					])
+					.help("Keep selecting and capturing until cancelled")
+					.hidden(!capture),
+			)
+			.arg(
+				Arg::with_name("follow")
+					.long("follow")
+					.conflicts_with("root")
+					.help("Follow the window if it is moved or resized")
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("viewport")
+					.long("viewport")
+					.value_name("WxH")
+					.help("Record an area that follows the mouse pointer")
+					.conflicts_with_all(&[
+						"focus",
+						"parent",
+						"size",
+						"drag",
+						"follow",
+						"all-monitors",
+						"window-id",
+						"window-title",
+						"window-class",
+						"pid",
+					])
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("easing")
+					.long("easing")
+					.value_name("FACTOR")
+					.default_value("0.25")
+					.help("Set the easing factor for moving the viewport")
+					.validator(|v| match v.parse::<f64>() {
+						Ok(easing) if easing > 0. && easing <= 1. => Ok(()),
+						_ => Err(String::from("Easing must be between 0 and 1")),
+					})
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("show-keys")
+					.long("show-keys")
+					.help("Show the pressed keys on the recording")
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("keys-corner")
+					.long("keys-corner")
+					.value_name("CORNER")
+					.help("Set the corner to show the pressed keys on")
+					.possible_values(&[
+						"top-left",
+						"top-right",
+						"bottom-left",
+						"bottom-right",
+					])
+					.default_value("bottom-left")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("show-clicks")
+					.long("show-clicks")
+					.help("Highlight the mouse clicks on the recording")
+					.hidden(capture),
+			)
 			.arg(
 				Arg::with_name("no-keys")
 					.long("no-keys")
@@ -260,6 +380,15 @@ where
 					.help("Set the cancel keys")
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("pause-keys")
+					.long("pause-keys")
+					.value_name("KEYS")
+					.default_value("LAlt-P")
+					.help("Set the keys for pausing/resuming the recording")
+					.takes_value(true)
+					.hidden(capture),
+			)
 			.arg(
 				Arg::with_name("mouse")
 					.short("m")
@@ -310,6 +439,32 @@ where
 					.takes_value(true)
 					.hidden(capture),
 			)
+			.arg(
+				Arg::with_name("replay")
+					.long("replay")
+					.value_name("S")
+					.help("Save only the last S seconds of the recording")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("sequence")
+					.long("sequence")
+					.value_name("DIR")
+					.help("Write the frames to a directory as an image sequence")
+					.takes_value(true)
+					.hidden(capture),
+			)
+			.arg(
+				Arg::with_name("sequence-format")
+					.long("sequence-format")
+					.value_name("FORMAT")
+					.possible_values(&["png", "qoi", "ppm"])
+					.default_value("png")
+					.help("Set the image format of the sequence")
+					.takes_value(true)
+					.hidden(capture),
+			)
 			.arg(
 				Arg::with_name("countdown")
 					.short("c")
@@ -342,6 +497,42 @@ where
 					.help("Set the refresh interval for window selection")
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("every")
+					.long("every")
+					.value_name("TIME")
+					.help("Capture an image periodically (e.g. 30s, 5m)")
+					.validator(|v| RecordTimelapse::parse_interval(&v).map(|_| ()))
+					.takes_value(true)
+					.hidden(!capture),
+			)
+			.arg(
+				Arg::with_name("count")
+					.long("count")
+					.value_name("N")
+					.default_value("\u{221E}")
+					.help("Set the number of periodic captures")
+					.takes_value(true)
+					.hidden(!capture),
+			)
+			.arg(
+				Arg::with_name("skip-similar")
+					.long("skip-similar")
+					.value_name("PIXELS")
+					.help("Skip the images that differ in at most PIXELS")
+					.requires("every")
+					.takes_value(true)
+					.hidden(!capture),
+			)
+			.arg(
+				Arg::with_name("animate")
+					.long("animate")
+					.value_name("FPS")
+					.help("Save the periodic captures as a GIF with the given FPS")
+					.requires("every")
+					.takes_value(true)
+					.hidden(!capture),
+			)
 			.arg(
 				Arg::with_name("font")
 					.long("font")
@@ -353,7 +544,7 @@ where
 			.arg(
 				Arg::with_name("monitor")
 					.long("monitor")
-					.value_name("NUM")
+					.value_name("MONITOR")
 					.help(if capture {
 						"Set the monitor to capture as root window"
 					} else {
@@ -361,6 +552,126 @@ where
 					})
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("all-monitors")
+					.long("all-monitors")
+					.conflicts_with_all(&[
+						"focus",
+						"monitor",
+						"window-id",
+						"window-title",
+						"window-class",
+						"pid",
+					])
+					.help(if capture {
+						"Capture each monitor to a separate file"
+					} else {
+						"Record each monitor to a separate file"
+					}),
+			)
+			.arg(
+				Arg::with_name("window-id")
+					.long("window-id")
+					.value_name("ID")
+					.help(if capture {
+						"Set the ID of the window to capture"
+					} else {
+						"Set the ID of the window to record"
+					})
+					.conflicts_with_all(&[
+						"root",
+						"focus",
+						"window-title",
+						"window-class",
+						"pid",
+					])
+					.validator(|v| {
+						WindowFilter::parse_id(&v)
+							.map(|_| ())
+							.ok_or_else(|| String::from("Invalid window ID"))
+					})
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("window-title")
+					.long("window-title")
+					.value_name("REGEX")
+					.help(if capture {
+						"Set the title of the window to capture"
+					} else {
+						"Set the title of the window to record"
+					})
+					.conflicts_with_all(&["root", "focus", "window-class", "pid"])
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("window-class")
+					.long("window-class")
+					.value_name("CLASS")
+					.help(if capture {
+						"Set the class of the window to capture"
+					} else {
+						"Set the class of the window to record"
+					})
+					.conflicts_with_all(&["root", "focus", "pid"])
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("pid")
+					.long("pid")
+					.value_name("PID")
+					.help(if capture {
+						"Set the process ID of the window to capture"
+					} else {
+						"Set the process ID of the window to record"
+					})
+					.conflicts_with_all(&["root", "focus"])
+					.validator(|v| {
+						v.parse::<u32>()
+							.map(|_| ())
+							.map_err(|_| String::from("Invalid process ID"))
+					})
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("wait-for-window")
+					.long("wait-for-window")
+					.value_name("PATTERN")
+					.help("Wait for a window that matches the title regex or class")
+					.conflicts_with_all(&[
+						"root",
+						"focus",
+						"all-monitors",
+						"window-id",
+						"window-title",
+						"window-class",
+						"pid",
+						"session",
+					])
+					.takes_value(true),
+			)
+	}
+
+	/**
+	 * Get the animation subcommands of the enabled formats.
+	 *
+	 * @param  mode
+	 * @return Vector of App
+	 */
+	fn get_anim_subcommands(mode: fn(AnimFormat) -> AnimMode) -> Vec<App<'a, 'b>> {
+		[
+			(AnimFormat::Gif, FileFormat::Gif),
+			(AnimFormat::Apng, FileFormat::Apng),
+			#[cfg(feature = "av1")]
+			(AnimFormat::Av1, FileFormat::Av1),
+			(AnimFormat::Avi, FileFormat::Avi),
+		]
+		.into_iter()
+		.map(|(anim_format, file_format)| {
+			Self::get_anim_args(mode(anim_format))
+				.subcommand(Self::get_save_args(file_format))
+		})
+		.collect()
 	}
 
 	/**
@@ -405,6 +716,28 @@ where
 					.value_name("REPEAT")
 					.default_value("\u{221E}")
 					.help("Set the number of repetitions")
+					.hidden(
+						mode.has_format(AnimFormat::Av1)
+							|| mode.has_format(AnimFormat::Avi),
+					)
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("memory-limit")
+					.long("memory-limit")
+					.value_name("MB")
+					.default_value("1024")
+					.help("Set the memory limit for storing frames")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("collapse")
+					.long("collapse")
+					.value_name("PIXELS")
+					.help(
+						"Merge the consecutive frames that differ in at most PIXELS",
+					)
+					.hidden(mode.is_edit() || mode == AnimMode::Make)
 					.takes_value(true),
 			)
 			.arg(
@@ -413,6 +746,8 @@ where
 					.help("Use the gifski encoder")
 					.hidden(
 						mode.has_format(AnimFormat::Apng)
+							|| mode.has_format(AnimFormat::Av1)
+							|| mode.has_format(AnimFormat::Avi)
 							|| cfg!(not(feature = "ski")),
 					),
 			)
@@ -422,9 +757,24 @@ where
 					.help("Encode 3 times faster (gifski)")
 					.hidden(
 						mode.has_format(AnimFormat::Apng)
+							|| mode.has_format(AnimFormat::Av1)
+							|| mode.has_format(AnimFormat::Avi)
 							|| cfg!(not(feature = "ski")),
 					),
 			)
+			.arg(
+				Arg::with_name("preset")
+					.long("preset")
+					.value_name("PRESET")
+					.default_value("8")
+					.help("Set the encoder speed preset (0-10)")
+					.validator(|v| match v.parse::<u8>() {
+						Ok(preset) if preset <= 10 => Ok(()),
+						_ => Err(String::from("Preset must be between 0 and 10")),
+					})
+					.hidden(!mode.has_format(AnimFormat::Av1))
+					.takes_value(true),
+			)
 			.arg(
 				Arg::with_name("speed")
 					.short("s")
@@ -486,7 +836,13 @@ where
 					.long("format")
 					.value_name("FORMAT")
 					.help("Set the animation format")
-					.possible_values(&["gif", "apng"])
+					.possible_values(&[
+						"gif",
+						"apng",
+						#[cfg(feature = "av1")]
+						"av1",
+						"avi",
+					])
 					.default_value("gif")
 					.hidden(mode != AnimMode::Make)
 					.takes_value(true),
@@ -695,6 +1051,40 @@ where
 			)
 	}
 
+	/**
+	 * Get list subcommand arguments.
+	 *
+	 * @return App
+	 */
+	fn get_list_args() -> App<'a, 'b> {
+		SubCommand::with_name("list")
+			.help_message("Print help information")
+			.about("List the windows and monitors")
+			.arg(
+				Arg::with_name("windows")
+					.short("w")
+					.long("windows")
+					.help("List only the windows"),
+			)
+			.arg(
+				Arg::with_name("monitors")
+					.short("m")
+					.long("monitors")
+					.conflicts_with("windows")
+					.help("List only the monitors"),
+			)
+			.arg(
+				Arg::with_name("format")
+					.short("f")
+					.long("format")
+					.value_name("FORMAT")
+					.possible_values(&["table", "json"])
+					.default_value("table")
+					.help("Set the output format")
+					.takes_value(true),
+			)
+	}
+
 	/**
 	 * Add image related subcommands to the given arguments.
 	 *
@@ -0,0 +1,103 @@
+use crate::app::AppResult;
+use crate::av1::Muxer;
+use crate::image::geometry::Geometry;
+use std::io::{Seek, SeekFrom, Write};
+
+/* Signature of the IVF header */
+const IVF_SIGNATURE: &[u8; 4] = b"DKIF";
+/* FourCC of the AV1 codec */
+const AV1_FOURCC: &[u8; 4] = b"AV01";
+/* Size of the IVF header in bytes */
+const HEADER_SIZE: u16 = 32;
+/* Offset of the frame count in the IVF header */
+const FRAME_COUNT_OFFSET: u64 = 24;
+
+/* Muxer for the IVF container */
+pub struct IvfMuxer<Output: Write + Seek> {
+	output: Output,
+	start: u64,
+	frame_count: u32,
+}
+
+impl<Output: Write + Seek> IvfMuxer<Output> {
+	/**
+	 * Create a new IvfMuxer object.
+	 *
+	 * @param  output
+	 * @return IvfMuxer
+	 */
+	pub fn new(output: Output) -> Self {
+		Self {
+			output,
+			start: 0,
+			frame_count: 0,
+		}
+	}
+}
+
+/* Muxer implementation for writing IVF files */
+impl<Output: Write + Seek> Muxer for IvfMuxer<Output> {
+	fn write_header(&mut self, geometry: Geometry, _: &[u8]) -> AppResult<()> {
+		self.start = self.output.stream_position()?;
+		self.output.write_all(IVF_SIGNATURE)?;
+		self.output.write_all(&0_u16.to_le_bytes())?;
+		self.output.write_all(&HEADER_SIZE.to_le_bytes())?;
+		self.output.write_all(AV1_FOURCC)?;
+		self.output
+			.write_all(&(geometry.width as u16).to_le_bytes())?;
+		self.output
+			.write_all(&(geometry.height as u16).to_le_bytes())?;
+		self.output.write_all(&1000_u32.to_le_bytes())?;
+		self.output.write_all(&1_u32.to_le_bytes())?;
+		self.output.write_all(&0_u32.to_le_bytes())?;
+		self.output.write_all(&0_u32.to_le_bytes())?;
+		Ok(())
+	}
+
+	fn write_frame(
+		&mut self,
+		data: &[u8],
+		timestamp: u64,
+		_: bool,
+	) -> AppResult<()> {
+		self.output.write_all(&(data.len() as u32).to_le_bytes())?;
+		self.output.write_all(&timestamp.to_le_bytes())?;
+		self.output.write_all(data)?;
+		self.frame_count += 1;
+		Ok(())
+	}
+
+	fn finish(&mut self, _: u64) -> AppResult<()> {
+		let end = self.output.stream_position()?;
+		self.output
+			.seek(SeekFrom::Start(self.start + FRAME_COUNT_OFFSET))?;
+		self.output.write_all(&self.frame_count.to_le_bytes())?;
+		self.output.seek(SeekFrom::Start(end))?;
+		self.output.flush()?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	use std::io::Cursor;
+	#[test]
+	fn test_ivf_muxer() -> AppResult<()> {
+		let mut output = Cursor::new(Vec::new());
+		let mut muxer = IvfMuxer::new(&mut output);
+		muxer.write_header(Geometry::new(0, 0, 320, 240), &[])?;
+		muxer.write_frame(&[1, 2, 3], 0, true)?;
+		muxer.write_frame(&[4, 5], 100, false)?;
+		muxer.finish(150)?;
+		let data = output.into_inner();
+		assert_eq!(b"DKIF", &data[..4]);
+		assert_eq!(b"AV01", &data[8..12]);
+		assert_eq!([64, 1, 240, 0], data[12..16]);
+		assert_eq!([2, 0, 0, 0], data[24..28]);
+		assert_eq!([3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3], data[32..47]);
+		assert_eq!([2, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 4, 5], data[47..]);
+		Ok(())
+	}
+}
@@ -0,0 +1,404 @@
+pub mod ivf;
+pub mod webm;
+
+use crate::anim::settings::AnimSettings;
+use crate::anim::store::FrameStore;
+use crate::anim::{self, TimedFrame};
+use crate::app::{AppError, AppResult};
+use crate::av1::ivf::IvfMuxer;
+use crate::av1::webm::WebmMuxer;
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use crate::util::state::InputState;
+use image::ExtendedColorType;
+use rav1e::color::{
+	ColorDescription, ColorPrimaries, MatrixCoefficients, PixelRange,
+	TransferCharacteristics,
+};
+use rav1e::data::{FrameType, Rational};
+use rav1e::{Config, Context, EncoderConfig, EncoderStatus};
+use std::ffi::OsStr;
+use std::io::{self, Seek, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/* Container that the encoded frames are written to */
+pub trait Muxer {
+	/**
+	 * Write the container header.
+	 *
+	 * @param  geometry
+	 * @param  codec_private
+	 * @return Result
+	 */
+	fn write_header(
+		&mut self,
+		geometry: Geometry,
+		codec_private: &[u8],
+	) -> AppResult<()>;
+
+	/**
+	 * Write an encoded frame.
+	 *
+	 * @param  data
+	 * @param  timestamp (ms)
+	 * @param  keyframe
+	 * @return Result
+	 */
+	fn write_frame(
+		&mut self,
+		data: &[u8],
+		timestamp: u64,
+		keyframe: bool,
+	) -> AppResult<()>;
+
+	/**
+	 * Finish writing the container.
+	 *
+	 * @param  duration (ms)
+	 * @return Result
+	 */
+	fn finish(&mut self, duration: u64) -> AppResult<()>;
+}
+
+/**
+ * Get the muxer for the given output path. (IVF or WebM)
+ *
+ * @param  path
+ * @param  output
+ * @return Muxer
+ */
+pub fn get_muxer<'a, Output: Write + Seek + 'a>(
+	path: &Path,
+	output: Output,
+) -> Box<dyn Muxer + 'a> {
+	if path.extension().and_then(OsStr::to_str) == Some("ivf") {
+		Box::new(IvfMuxer::new(output))
+	} else {
+		Box::new(WebmMuxer::new(output))
+	}
+}
+
+/* AV1 encoder and settings */
+pub struct Av1Encoder<'a> {
+	context: Context<u8>,
+	geometry: Geometry,
+	muxer: Box<dyn Muxer + 'a>,
+	timestamps: Vec<u64>,
+	duration: u64,
+}
+
+impl<'a> Av1Encoder<'a> {
+	/**
+	 * Create a new Av1Encoder object.
+	 *
+	 * @param  geometry
+	 * @param  muxer
+	 * @param  settings
+	 * @return Av1Encoder (Result)
+	 */
+	pub fn new(
+		geometry: Geometry,
+		mut muxer: Box<dyn Muxer + 'a>,
+		settings: &AnimSettings,
+	) -> AppResult<Self> {
+		let config = EncoderConfig {
+			width: geometry.width as usize,
+			height: geometry.height as usize,
+			time_base: Rational::new(1, settings.fps.into()),
+			quantizer: usize::from(100 - settings.quality.clamp(1, 100)) * 255 / 100,
+			pixel_range: PixelRange::Limited,
+			color_description: Some(ColorDescription {
+				color_primaries: ColorPrimaries::BT709,
+				transfer_characteristics: TransferCharacteristics::SRGB,
+				matrix_coefficients: MatrixCoefficients::BT601,
+			}),
+			..EncoderConfig::with_speed_preset(settings.preset)
+		};
+		let context = Config::new()
+			.with_encoder_config(config)
+			.with_threads(0)
+			.new_context()?;
+		muxer.write_header(geometry, &context.container_sequence_header())?;
+		Ok(Self {
+			context,
+			geometry,
+			muxer,
+			timestamps: Vec::new(),
+			duration: 0,
+		})
+	}
+
+	/**
+	 * Encode images as frame and write to the file.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		mut self,
+		images: FrameStore,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		for i in 0..images.len() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					return Err(AppError::FrameError(String::from(
+						"Failed to write the frames",
+					)));
+				}
+			}
+			self.encode_frame(&images.get(i)?, images.get_delay(i))?;
+		}
+		info!("\n");
+		self.finish()
+	}
+
+	/**
+	 * Encode images as frame while they are received.
+	 *
+	 * @param  frames
+	 * @return Result
+	 */
+	pub fn stream<Frames: Iterator<Item = TimedFrame>>(
+		mut self,
+		frames: Frames,
+	) -> AppResult<()> {
+		for (image, delay) in frames {
+			self.encode_frame(&image, delay)?;
+		}
+		self.finish()
+	}
+
+	/**
+	 * Send a frame to the encoder and write the encoded packets.
+	 *
+	 * @param  image
+	 * @param  delay
+	 * @return Result
+	 */
+	fn encode_frame(&mut self, image: &Image, delay: Duration) -> AppResult<()> {
+		anim::check_frame_size(image, self.geometry)?;
+		let (y, u, v) = get_yuv_planes(image);
+		let width = self.geometry.width as usize;
+		let mut frame = self.context.new_frame();
+		frame.planes[0].copy_from_raw_u8(&y, width, 1);
+		frame.planes[1].copy_from_raw_u8(&u, width.div_ceil(2), 1);
+		frame.planes[2].copy_from_raw_u8(&v, width.div_ceil(2), 1);
+		self.context.send_frame(frame)?;
+		self.timestamps.push(self.duration);
+		self.duration += delay.as_millis() as u64;
+		self.write_packets()
+	}
+
+	/**
+	 * Write the packets that are ready to the muxer.
+	 *
+	 * @return Result
+	 */
+	fn write_packets(&mut self) -> AppResult<()> {
+		loop {
+			match self.context.receive_packet() {
+				Ok(packet) => self.muxer.write_frame(
+					&packet.data,
+					self.timestamps
+						.get(packet.input_frameno as usize)
+						.copied()
+						.unwrap_or(self.duration),
+					packet.frame_type == FrameType::KEY,
+				)?,
+				Err(EncoderStatus::Encoded) => {}
+				Err(EncoderStatus::NeedMoreData | EncoderStatus::LimitReached) => {
+					return Ok(())
+				}
+				Err(e) => return Err(e.into()),
+			}
+		}
+	}
+
+	/**
+	 * Flush the encoder and finish writing the file.
+	 *
+	 * @return Result
+	 */
+	fn finish(mut self) -> AppResult<()> {
+		self.context.flush();
+		self.write_packets()?;
+		self.muxer.finish(self.duration)
+	}
+}
+
+/**
+ * Convert the image to YUV 4:2:0 planes. (BT.601, limited range)
+ *
+ * @param  image
+ * @return Tuple of Y, U and V planes
+ */
+fn get_yuv_planes(image: &Image) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+	let (width, height) = (
+		image.geometry.width as usize,
+		image.geometry.height as usize,
+	);
+	let rgb = image
+		.get_data(ExtendedColorType::Rgb8)
+		.chunks_exact(3)
+		.map(|v| (i32::from(v[0]), i32::from(v[1]), i32::from(v[2])))
+		.collect::<Vec<(i32, i32, i32)>>();
+	let y = rgb
+		.iter()
+		.map(|(r, g, b)| (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8)
+		.collect();
+	let (chroma_width, chroma_height) = (width.div_ceil(2), height.div_ceil(2));
+	let mut u = Vec::with_capacity(chroma_width * chroma_height);
+	let mut v = Vec::with_capacity(chroma_width * chroma_height);
+	for row in 0..chroma_height {
+		for column in 0..chroma_width {
+			let pixels = [(0, 0), (0, 1), (1, 0), (1, 1)]
+				.iter()
+				.map(|(dy, dx)| {
+					rgb[(row * 2 + dy).min(height - 1) * width
+						+ (column * 2 + dx).min(width - 1)]
+				})
+				.fold((0, 0, 0), |sum, (r, g, b)| {
+					(sum.0 + r, sum.1 + g, sum.2 + b)
+				});
+			let (r, g, b) = (pixels.0 / 4, pixels.1 / 4, pixels.2 / 4);
+			u.push((((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8);
+			v.push((((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8);
+		}
+	}
+	(y, u, v)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::Rgba;
+	use pretty_assertions::assert_eq;
+	use std::io::Cursor;
+	/**
+	 * Read the ID and size of the EBML element at the given offset.
+	 *
+	 * @param  data
+	 * @param  offset
+	 * @return Tuple of ID, size and the offset of the element data
+	 */
+	fn read_element(data: &[u8], offset: usize) -> ((u32, usize), usize) {
+		let id_length = data[offset].leading_zeros() as usize + 1;
+		let id = data[offset..offset + id_length]
+			.iter()
+			.fold(0, |v, byte| (v << 8) | u32::from(*byte));
+		let offset = offset + id_length;
+		let size_length = data[offset].leading_zeros() as usize + 1;
+		let size = data[offset..offset + size_length]
+			.iter()
+			.fold(0, |v, byte| (v << 8) | u64::from(*byte))
+			& ((1 << (7 * size_length)) - 1);
+		((id, size as usize), offset + size_length)
+	}
+	#[test]
+	fn test_av1_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 17, 17);
+		let frames = (0..4)
+			.map(|i| {
+				(
+					Image::new(
+						(0..17 * 17)
+							.map(|v| Rgba::from([(v + i * 40) as u8, 128, 0, 255]))
+							.collect(),
+						false,
+						geometry,
+					),
+					Duration::from_millis(40),
+				)
+			})
+			.collect::<Vec<TimedFrame>>();
+		let (y, u, v) = get_yuv_planes(&Image::new(
+			vec![Rgba::from([0, 0, 255, 255]); 17 * 17],
+			false,
+			geometry,
+		));
+		assert_eq!((17 * 17, 9 * 9, 9 * 9), (y.len(), u.len(), v.len()));
+		assert_eq!((41, 240, 110), (y[0], u[0], v[0]));
+		let settings = AnimSettings {
+			fps: 25,
+			preset: 10,
+			..AnimSettings::default()
+		};
+		let mut output = Cursor::new(Vec::new());
+		Av1Encoder::new(
+			geometry,
+			get_muxer(Path::new("test.ivf"), &mut output),
+			&settings,
+		)?
+		.stream(frames.clone().into_iter())?;
+		let data = output.into_inner();
+		assert_eq!(b"DKIF", &data[..4]);
+		assert_eq!(b"AV01", &data[8..12]);
+		assert_eq!([17, 0, 17, 0], data[12..16]);
+		assert_eq!([4, 0, 0, 0], data[24..28]);
+		let mut offset = 32;
+		let mut timestamps = Vec::new();
+		while offset < data.len() {
+			let size =
+				u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+			timestamps.push(u64::from_le_bytes(
+				data[offset + 4..offset + 12].try_into().unwrap(),
+			));
+			offset += 12 + size as usize;
+		}
+		assert_eq!(data.len(), offset);
+		assert_eq!(vec![0, 40, 80, 120], timestamps);
+		let mut output = Cursor::new(Vec::new());
+		Av1Encoder::new(
+			geometry,
+			get_muxer(Path::new("test.webm"), &mut output),
+			&settings,
+		)?
+		.save(FrameStore::from_frames(frames, 0)?, None)?;
+		let data = output.into_inner();
+		assert_eq!([0x1A, 0x45, 0xDF, 0xA3], data[..4]);
+		assert!(data.windows(4).any(|v| v == b"webm"));
+		let ((_, size), ebml) = read_element(&data, 0);
+		let ((segment, _), mut offset) = read_element(&data, ebml + size);
+		assert_eq!(0x1853_8067, segment);
+		let mut timestamps = Vec::new();
+		while offset < data.len() {
+			let ((id, size), start) = read_element(&data, offset);
+			offset = start + size;
+			if id != 0x1F43_B675 {
+				continue;
+			}
+			let ((_, size), timestamp) = read_element(&data, start);
+			let cluster_timestamp = data[timestamp..timestamp + size]
+				.iter()
+				.fold(0, |v, byte| (v << 8) | u64::from(*byte));
+			let mut block = timestamp + size;
+			while block < offset {
+				let ((id, size), start) = read_element(&data, block);
+				assert_eq!(0xA3, id);
+				timestamps.push(
+					cluster_timestamp
+						+ i16::from_be_bytes([data[start + 1], data[start + 2]])
+							as u64,
+				);
+				block = start + size;
+			}
+		}
+		assert_eq!(data.len(), offset);
+		assert_eq!(vec![0, 40, 80, 120], timestamps);
+		Ok(())
+	}
+}
@@ -0,0 +1,260 @@
+use crate::app::AppResult;
+use crate::av1::Muxer;
+use crate::image::geometry::Geometry;
+use std::io::{Seek, SeekFrom, Write};
+
+/* EBML element IDs that are used in the WebM file */
+const EBML: u32 = 0x1A45_DFA3;
+const EBML_VERSION: u32 = 0x4286;
+const EBML_READ_VERSION: u32 = 0x42F7;
+const EBML_MAX_ID_LENGTH: u32 = 0x42F2;
+const EBML_MAX_SIZE_LENGTH: u32 = 0x42F3;
+const DOC_TYPE: u32 = 0x4282;
+const DOC_TYPE_VERSION: u32 = 0x4287;
+const DOC_TYPE_READ_VERSION: u32 = 0x4285;
+const SEGMENT: u32 = 0x1853_8067;
+const INFO: u32 = 0x1549_A966;
+const TIMESTAMP_SCALE: u32 = 0x2A_D7B1;
+const MUXING_APP: u32 = 0x4D80;
+const WRITING_APP: u32 = 0x5741;
+const DURATION: u32 = 0x4489;
+const TRACKS: u32 = 0x1654_AE6B;
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_NUMBER: u32 = 0xD7;
+const TRACK_UID: u32 = 0x73C5;
+const TRACK_TYPE: u32 = 0x83;
+const CODEC_ID: u32 = 0x86;
+const CODEC_PRIVATE: u32 = 0x63A2;
+const VIDEO: u32 = 0xE0;
+const PIXEL_WIDTH: u32 = 0xB0;
+const PIXEL_HEIGHT: u32 = 0xBA;
+const CLUSTER: u32 = 0x1F43_B675;
+const TIMESTAMP: u32 = 0xE7;
+const SIMPLE_BLOCK: u32 = 0xA3;
+/* Size value of the elements with unknown size */
+const UNKNOWN_SIZE: u64 = 0x01FF_FFFF_FFFF_FFFF;
+
+/* Muxer for the WebM container */
+pub struct WebmMuxer<Output: Write + Seek> {
+	output: Output,
+	segment: u64,
+	duration: u64,
+	cluster: Vec<u8>,
+	cluster_timestamp: u64,
+}
+
+impl<Output: Write + Seek> WebmMuxer<Output> {
+	/**
+	 * Create a new WebmMuxer object.
+	 *
+	 * @param  output
+	 * @return WebmMuxer
+	 */
+	pub fn new(output: Output) -> Self {
+		Self {
+			output,
+			segment: 0,
+			duration: 0,
+			cluster: Vec::new(),
+			cluster_timestamp: 0,
+		}
+	}
+
+	/**
+	 * Write the buffered cluster to the output.
+	 *
+	 * @return Result
+	 */
+	fn write_cluster(&mut self) -> AppResult<()> {
+		if !self.cluster.is_empty() {
+			let mut data = Vec::new();
+			write_element(&mut data, TIMESTAMP, &uint(self.cluster_timestamp));
+			data.append(&mut self.cluster);
+			let mut cluster = Vec::new();
+			write_element(&mut cluster, CLUSTER, &data);
+			self.output.write_all(&cluster)?;
+		}
+		Ok(())
+	}
+}
+
+/* Muxer implementation for writing WebM files */
+impl<Output: Write + Seek> Muxer for WebmMuxer<Output> {
+	fn write_header(
+		&mut self,
+		geometry: Geometry,
+		codec_private: &[u8],
+	) -> AppResult<()> {
+		let mut ebml = Vec::new();
+		write_element(&mut ebml, EBML_VERSION, &uint(1));
+		write_element(&mut ebml, EBML_READ_VERSION, &uint(1));
+		write_element(&mut ebml, EBML_MAX_ID_LENGTH, &uint(4));
+		write_element(&mut ebml, EBML_MAX_SIZE_LENGTH, &uint(8));
+		write_element(&mut ebml, DOC_TYPE, b"webm");
+		write_element(&mut ebml, DOC_TYPE_VERSION, &uint(4));
+		write_element(&mut ebml, DOC_TYPE_READ_VERSION, &uint(2));
+		let mut header = Vec::new();
+		write_element(&mut header, EBML, &ebml);
+		write_id(&mut header, SEGMENT);
+		header.extend(UNKNOWN_SIZE.to_be_bytes());
+		self.segment = self.output.stream_position()? + header.len() as u64;
+		let mut info = Vec::new();
+		write_element(&mut info, TIMESTAMP_SCALE, &uint(1_000_000));
+		write_element(&mut info, MUXING_APP, env!("CARGO_PKG_NAME").as_bytes());
+		write_element(&mut info, WRITING_APP, env!("CARGO_PKG_NAME").as_bytes());
+		write_element(&mut info, DURATION, &0_f64.to_be_bytes());
+		self.duration =
+			self.segment + header_size(INFO, info.len()) + info.len() as u64 - 8;
+		write_element(&mut header, INFO, &info);
+		let mut video = Vec::new();
+		write_element(&mut video, PIXEL_WIDTH, &uint(geometry.width.into()));
+		write_element(&mut video, PIXEL_HEIGHT, &uint(geometry.height.into()));
+		let mut track = Vec::new();
+		write_element(&mut track, TRACK_NUMBER, &uint(1));
+		write_element(&mut track, TRACK_UID, &uint(1));
+		write_element(&mut track, TRACK_TYPE, &uint(1));
+		write_element(&mut track, CODEC_ID, b"V_AV1");
+		write_element(&mut track, CODEC_PRIVATE, codec_private);
+		write_element(&mut track, VIDEO, &video);
+		let mut tracks = Vec::new();
+		write_element(&mut tracks, TRACK_ENTRY, &track);
+		write_element(&mut header, TRACKS, &tracks);
+		self.output.write_all(&header)?;
+		Ok(())
+	}
+
+	fn write_frame(
+		&mut self,
+		data: &[u8],
+		timestamp: u64,
+		keyframe: bool,
+	) -> AppResult<()> {
+		if self.cluster.is_empty()
+			|| keyframe
+			|| timestamp - self.cluster_timestamp > i16::MAX as u64
+		{
+			self.write_cluster()?;
+			self.cluster_timestamp = timestamp;
+		}
+		let mut block = vec![0x81];
+		block.extend(((timestamp - self.cluster_timestamp) as i16).to_be_bytes());
+		block.push(if keyframe { 0x80 } else { 0x00 });
+		block.extend(data);
+		write_element(&mut self.cluster, SIMPLE_BLOCK, &block);
+		Ok(())
+	}
+
+	fn finish(&mut self, duration: u64) -> AppResult<()> {
+		self.write_cluster()?;
+		let end = self.output.stream_position()?;
+		self.output.seek(SeekFrom::Start(self.segment - 8))?;
+		self.output
+			.write_all(&((1 << 56) | (end - self.segment)).to_be_bytes())?;
+		self.output.seek(SeekFrom::Start(self.duration))?;
+		self.output.write_all(&(duration as f64).to_be_bytes())?;
+		self.output.seek(SeekFrom::Start(end))?;
+		self.output.flush()?;
+		Ok(())
+	}
+}
+
+/**
+ * Write the EBML element with the given ID and data.
+ *
+ * @param  buffer
+ * @param  id
+ * @param  data
+ */
+fn write_element(buffer: &mut Vec<u8>, id: u32, data: &[u8]) {
+	write_id(buffer, id);
+	write_size(buffer, data.len() as u64);
+	buffer.extend(data);
+}
+
+/**
+ * Write the EBML element ID.
+ *
+ * @param  buffer
+ * @param  id
+ */
+fn write_id(buffer: &mut Vec<u8>, id: u32) {
+	buffer.extend(id.to_be_bytes().iter().skip_while(|v| **v == 0));
+}
+
+/**
+ * Write the size of the EBML element as a variable size integer.
+ *
+ * @param  buffer
+ * @param  size
+ */
+fn write_size(buffer: &mut Vec<u8>, size: u64) {
+	let length = (1..8).find(|i| size < (1 << (7 * i)) - 1).unwrap_or(8);
+	buffer.extend(&((1 << (7 * length)) | size).to_be_bytes()[8 - length..]);
+}
+
+/**
+ * Get the size of the EBML element header. (ID + size)
+ *
+ * @param  id
+ * @param  size
+ * @return u64
+ */
+fn header_size(id: u32, size: usize) -> u64 {
+	let mut buffer = Vec::new();
+	write_id(&mut buffer, id);
+	write_size(&mut buffer, size as u64);
+	buffer.len() as u64
+}
+
+/**
+ * Get the bytes of an unsigned integer element.
+ *
+ * @param  value
+ * @return Vector of u8
+ */
+fn uint(value: u64) -> Vec<u8> {
+	let bytes = value.to_be_bytes();
+	let start = bytes.iter().position(|v| *v != 0).unwrap_or(7);
+	bytes[start..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	use std::io::Cursor;
+	#[test]
+	fn test_webm_muxer() -> AppResult<()> {
+		let mut buffer = Vec::new();
+		write_size(&mut buffer, 0x7E);
+		write_size(&mut buffer, 0x7F);
+		assert_eq!(vec![0xFE, 0x40, 0x7F], buffer);
+		assert_eq!(vec![0], uint(0));
+		assert_eq!(vec![0x0F, 0x42, 0x40], uint(1_000_000));
+		let mut output = Cursor::new(Vec::new());
+		let mut muxer = WebmMuxer::new(&mut output);
+		muxer.write_header(Geometry::new(0, 0, 320, 240), &[0x81, 0, 0, 0])?;
+		muxer.write_frame(&[1, 2, 3], 0, true)?;
+		muxer.write_frame(&[4, 5], 100, false)?;
+		muxer.finish(150)?;
+		let segment = muxer.segment as usize;
+		let duration = muxer.duration as usize;
+		let data = output.into_inner();
+		assert_eq!([0x1A, 0x45, 0xDF, 0xA3], data[..4]);
+		assert_eq!(
+			(data.len() - segment) as u64,
+			u64::from_be_bytes(data[segment - 8..segment].try_into().unwrap())
+				& !(1 << 56)
+		);
+		assert_eq!(
+			150.,
+			f64::from_be_bytes(data[duration..duration + 8].try_into().unwrap())
+		);
+		assert!(data.windows(5).any(|v| v == b"V_AV1"));
+		assert_eq!(
+			[0xA3, 0x86, 0x81, 0x00, 0x64, 0x00, 0x04, 0x05],
+			data[data.len() - 8..]
+		);
+		Ok(())
+	}
+}
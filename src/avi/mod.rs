@@ -0,0 +1,557 @@
+use crate::anim::store::FrameStore;
+use crate::anim::{self, TimedFrame};
+use crate::app::{AppError, AppResult};
+use crate::image::geometry::Geometry;
+use crate::image::settings::JpgSettings;
+use crate::image::Image;
+use crate::util::state::InputState;
+use image::codecs::jpeg::JpegEncoder;
+use image::{ColorType, ExtendedColorType};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+/* ID of the compressed video frame chunks */
+const FRAME_CHUNK: &[u8; 4] = b"00dc";
+/* FourCC of the Motion JPEG codec */
+const MJPG_FOURCC: &[u8; 4] = b"MJPG";
+/* Flag for indicating that the file has an index */
+const AVIF_HASINDEX: u32 = 0x10;
+/* Flag for indicating that the frame is a keyframe */
+const AVIIF_KEYFRAME: u32 = 0x10;
+/* Flag of the standard index entries that are not keyframes */
+const AVI_INDEX_DELTA_FRAME: u32 = 0x8000_0000;
+/* Index types of the OpenDML super index and standard index */
+const AVI_INDEX_OF_INDEXES: u8 = 0x00;
+const AVI_INDEX_OF_CHUNKS: u8 = 0x01;
+/* Number of the reserved super index entries (one per RIFF) */
+const SUPER_INDEX_SIZE: u32 = 256;
+/* Maximum size of a RIFF before continuing with an AVIX list */
+const RIFF_SIZE_LIMIT: u64 = 1 << 30;
+/* Offsets of the values that are updated after writing the frames */
+const RIFF_SIZE_OFFSET: u64 = 4;
+const TOTAL_FRAMES_OFFSET: u64 = 48;
+const AVIH_BUFFER_SIZE_OFFSET: u64 = 60;
+const LENGTH_OFFSET: u64 = 140;
+const STRH_BUFFER_SIZE_OFFSET: u64 = 144;
+const SUPER_INDEX_OFFSET: u64 = 224;
+const SUPER_INDEX_ENTRIES_OFFSET: u64 = 244;
+const DMLH_FRAMES_OFFSET: u64 =
+	SUPER_INDEX_ENTRIES_OFFSET + 16 * SUPER_INDEX_SIZE as u64 + 20;
+
+/* Motion JPEG (AVI) encoder and settings */
+pub struct AviEncoder<Output: Write + Seek> {
+	output: Output,
+	geometry: Geometry,
+	fps: u32,
+	quality: u8,
+	start: u64,
+	riff: u64,
+	movi: u64,
+	riff_limit: u64,
+	index: Vec<(u64, u32)>,
+	super_index: Vec<(u64, u32, u32)>,
+	first_frames: Option<u32>,
+	frame_count: u32,
+	buffer_size: u32,
+	time: Duration,
+}
+
+impl<Output: Write + Seek> AviEncoder<Output> {
+	/**
+	 * Create a new AviEncoder object.
+	 *
+	 * @param  geometry
+	 * @param  output
+	 * @param  fps
+	 * @param  settings
+	 * @return AviEncoder (Result)
+	 */
+	pub fn new(
+		geometry: Geometry,
+		mut output: Output,
+		fps: u32,
+		settings: &JpgSettings,
+	) -> AppResult<Self> {
+		let start = output.stream_position()?;
+		let mut encoder = Self {
+			output,
+			geometry,
+			fps: fps.max(1),
+			quality: settings.quality,
+			start,
+			riff: 0,
+			movi: 0,
+			riff_limit: RIFF_SIZE_LIMIT,
+			index: Vec::new(),
+			super_index: Vec::new(),
+			first_frames: None,
+			frame_count: 0,
+			buffer_size: 0,
+			time: Duration::ZERO,
+		};
+		encoder.write_header()?;
+		Ok(encoder)
+	}
+
+	/**
+	 * Write the AVI header with the placeholder sizes.
+	 *
+	 * @return Result
+	 */
+	fn write_header(&mut self) -> AppResult<()> {
+		let (width, height) = (self.geometry.width, self.geometry.height);
+		let mut avih = Vec::new();
+		for value in [
+			1_000_000 / self.fps,
+			0,
+			0,
+			AVIF_HASINDEX,
+			0,
+			0,
+			1,
+			0,
+			width,
+			height,
+			0,
+			0,
+			0,
+			0,
+		] {
+			avih.extend(value.to_le_bytes());
+		}
+		let mut strh = Vec::new();
+		strh.extend(b"vids");
+		strh.extend(MJPG_FOURCC);
+		strh.extend([0; 8]);
+		for value in [0, 1, self.fps, 0, 0, 0, u32::MAX, 0] {
+			strh.extend(value.to_le_bytes());
+		}
+		for value in [0, 0, width, height] {
+			strh.extend((value as u16).to_le_bytes());
+		}
+		let mut strf = Vec::new();
+		strf.extend(40_u32.to_le_bytes());
+		strf.extend(width.to_le_bytes());
+		strf.extend(height.to_le_bytes());
+		strf.extend(1_u16.to_le_bytes());
+		strf.extend(24_u16.to_le_bytes());
+		strf.extend(MJPG_FOURCC);
+		strf.extend((width * height * 3).to_le_bytes());
+		strf.extend([0; 16]);
+		let mut indx = Vec::new();
+		indx.extend(4_u16.to_le_bytes());
+		indx.extend([0, AVI_INDEX_OF_INDEXES]);
+		indx.extend(0_u32.to_le_bytes());
+		indx.extend(FRAME_CHUNK);
+		indx.extend([0; 12]);
+		indx.extend(vec![0; 16 * SUPER_INDEX_SIZE as usize]);
+		let mut strl = Vec::new();
+		write_chunk(&mut strl, b"strh", &strh);
+		write_chunk(&mut strl, b"strf", &strf);
+		write_chunk(&mut strl, b"indx", &indx);
+		let mut odml = Vec::new();
+		write_chunk(&mut odml, b"dmlh", &[0; 248]);
+		let mut hdrl = Vec::new();
+		write_chunk(&mut hdrl, b"avih", &avih);
+		write_list(&mut hdrl, b"strl", &strl);
+		write_list(&mut hdrl, b"odml", &odml);
+		let mut header = Vec::new();
+		header.extend(b"RIFF");
+		header.extend(0_u32.to_le_bytes());
+		header.extend(b"AVI ");
+		write_list(&mut header, b"hdrl", &hdrl);
+		self.output.write_all(&header)?;
+		self.begin_movi()
+	}
+
+	/**
+	 * Start a new RIFF (AVIX) for the frames that exceed the size limit.
+	 *
+	 * @return Result
+	 */
+	fn begin_riff(&mut self) -> AppResult<()> {
+		self.riff = self.get_position()?;
+		self.output.write_all(b"RIFF")?;
+		self.output.write_all(&0_u32.to_le_bytes())?;
+		self.output.write_all(b"AVIX")?;
+		self.begin_movi()
+	}
+
+	/**
+	 * Start the 'movi' list that contains the frames.
+	 *
+	 * @return Result
+	 */
+	fn begin_movi(&mut self) -> AppResult<()> {
+		self.output.write_all(b"LIST")?;
+		self.output.write_all(&0_u32.to_le_bytes())?;
+		self.movi = self.get_position()?;
+		self.output.write_all(b"movi")?;
+		Ok(())
+	}
+
+	/**
+	 * Write the indexes of the current RIFF and update its sizes.
+	 *
+	 * @return Result
+	 */
+	fn end_riff(&mut self) -> AppResult<()> {
+		let mut ix00 = Vec::new();
+		ix00.extend(2_u16.to_le_bytes());
+		ix00.extend([0, AVI_INDEX_OF_CHUNKS]);
+		ix00.extend((self.index.len() as u32).to_le_bytes());
+		ix00.extend(FRAME_CHUNK);
+		ix00.extend((self.start + self.movi).to_le_bytes());
+		ix00.extend(0_u32.to_le_bytes());
+		for (offset, size) in &self.index {
+			ix00.extend(((offset + 8 - self.movi) as u32).to_le_bytes());
+			ix00.extend(
+				if *size == 0 {
+					AVI_INDEX_DELTA_FRAME
+				} else {
+					*size
+				}
+				.to_le_bytes(),
+			);
+		}
+		let mut chunk = Vec::new();
+		write_chunk(&mut chunk, b"ix00", &ix00);
+		let position = self.get_position()?;
+		self.super_index.push((
+			self.start + position,
+			chunk.len() as u32,
+			self.index.len() as u32,
+		));
+		self.output.write_all(&chunk)?;
+		let movi_end = self.get_position()?;
+		if self.first_frames.is_none() {
+			let mut idx1 = Vec::new();
+			for (offset, size) in &self.index {
+				idx1.extend(FRAME_CHUNK);
+				for value in [
+					if *size == 0 { 0 } else { AVIIF_KEYFRAME },
+					(offset - self.movi) as u32,
+					*size,
+				] {
+					idx1.extend(value.to_le_bytes());
+				}
+			}
+			let mut chunk = Vec::new();
+			write_chunk(&mut chunk, b"idx1", &idx1);
+			self.output.write_all(&chunk)?;
+			self.first_frames = Some(self.index.len() as u32);
+		}
+		let end = self.get_position()?;
+		self.write_value(self.movi - 4, (movi_end - self.movi) as u32)?;
+		self.write_value(
+			self.riff + RIFF_SIZE_OFFSET,
+			(end - self.riff - 8) as u32,
+		)?;
+		self.output.seek(SeekFrom::Start(self.start + end))?;
+		self.index.clear();
+		Ok(())
+	}
+
+	/**
+	 * Encode images as frame and write to the AVI file.
+	 *
+	 * @param  images
+	 * @param  input_state (Option)
+	 * @return Result
+	 */
+	pub fn save(
+		mut self,
+		images: FrameStore,
+		input_state: Option<&'static InputState>,
+	) -> AppResult<()> {
+		for i in 0..images.len() {
+			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
+			info!("Saving... ({:.1}%)\r", percentage);
+			debug!(
+				"Encoding... ({:.1}%) [{}/{}]\r",
+				percentage,
+				i + 1,
+				images.len()
+			);
+			io::stdout().flush()?;
+			if let Some(state) = input_state {
+				if state.check_cancel_keys() {
+					info!("\n");
+					warn!("User interrupt detected.");
+					return Err(AppError::FrameError(String::from(
+						"Failed to write the frames",
+					)));
+				}
+			}
+			if let Err(e) = self.write_frame(&images.get(i)?, images.get_delay(i)) {
+				self.finish()?;
+				return Err(e);
+			}
+		}
+		info!("\n");
+		self.finish()
+	}
+
+	/**
+	 * Encode images as frame while they are received.
+	 *
+	 * @param  frames
+	 * @return Result
+	 */
+	pub fn stream<Frames: Iterator<Item = TimedFrame>>(
+		mut self,
+		frames: Frames,
+	) -> AppResult<()> {
+		for (image, delay) in frames {
+			if let Err(e) = self.write_frame(&image, delay) {
+				self.finish()?;
+				return Err(e);
+			}
+		}
+		self.finish()
+	}
+
+	/**
+	 * Encode the image as JPEG and write it as frame(s).
+	 *
+	 * The frame is repeated (or dropped) with respect to the elapsed
+	 * time since AVI streams have a constant frame rate. Repeated
+	 * frames are written as empty chunks.
+	 *
+	 * @param  image
+	 * @param  delay
+	 * @return Result
+	 */
+	fn write_frame(&mut self, image: &Image, delay: Duration) -> AppResult<()> {
+		anim::check_frame_size(image, self.geometry)?;
+		self.time += delay;
+		let mut count = ((self.time.as_secs_f64() * f64::from(self.fps)).round()
+			as u32)
+			.saturating_sub(self.frame_count);
+		if self.frame_count == 0 {
+			count = count.max(1);
+		}
+		if count == 0 {
+			return Ok(());
+		}
+		let mut data = Vec::new();
+		JpegEncoder::new_with_quality(&mut data, self.quality).encode(
+			&image.get_data(ExtendedColorType::Rgb8),
+			image.geometry.width,
+			image.geometry.height,
+			ColorType::Rgb8,
+		)?;
+		let mut position = self.get_position()?;
+		if !self.index.is_empty()
+			&& position - self.riff + data.len() as u64 > self.riff_limit
+		{
+			if self.super_index.len() + 1 >= SUPER_INDEX_SIZE as usize {
+				return Err(AppError::FrameError(String::from(
+					"AVI file size limit exceeded",
+				)));
+			}
+			self.end_riff()?;
+			self.begin_riff()?;
+			position = self.get_position()?;
+		}
+		let mut chunk = Vec::new();
+		write_chunk(&mut chunk, FRAME_CHUNK, &data);
+		self.index.push((position, data.len() as u32));
+		for _ in 1..count {
+			self.index.push((position + chunk.len() as u64, 0));
+			write_chunk(&mut chunk, FRAME_CHUNK, &[]);
+		}
+		self.output.write_all(&chunk)?;
+		self.frame_count += count;
+		self.buffer_size = self.buffer_size.max(data.len() as u32 + 8);
+		Ok(())
+	}
+
+	/**
+	 * Write the remaining index and update the values in the header.
+	 *
+	 * @return Result
+	 */
+	fn finish(mut self) -> AppResult<()> {
+		self.end_riff()?;
+		let end = self.get_position()?;
+		for (offset, value) in [
+			(TOTAL_FRAMES_OFFSET, self.first_frames.unwrap_or_default()),
+			(AVIH_BUFFER_SIZE_OFFSET, self.buffer_size),
+			(LENGTH_OFFSET, self.frame_count),
+			(STRH_BUFFER_SIZE_OFFSET, self.buffer_size),
+			(SUPER_INDEX_OFFSET, self.super_index.len() as u32),
+			(DMLH_FRAMES_OFFSET, self.frame_count),
+		] {
+			self.write_value(offset, value)?;
+		}
+		let mut entries = Vec::new();
+		for (offset, size, duration) in &self.super_index {
+			entries.extend(offset.to_le_bytes());
+			entries.extend(size.to_le_bytes());
+			entries.extend(duration.to_le_bytes());
+		}
+		self.output
+			.seek(SeekFrom::Start(self.start + SUPER_INDEX_ENTRIES_OFFSET))?;
+		self.output.write_all(&entries)?;
+		self.output.seek(SeekFrom::Start(self.start + end))?;
+		self.output.flush()?;
+		Ok(())
+	}
+
+	/**
+	 * Get the current position relative to the start of the file.
+	 *
+	 * @return u64 (Result)
+	 */
+	fn get_position(&mut self) -> AppResult<u64> {
+		Ok(self.output.stream_position()? - self.start)
+	}
+
+	/**
+	 * Write the value at the given offset.
+	 *
+	 * @param  offset
+	 * @param  value
+	 * @return Result
+	 */
+	fn write_value(&mut self, offset: u64, value: u32) -> AppResult<()> {
+		self.output.seek(SeekFrom::Start(self.start + offset))?;
+		self.output.write_all(&value.to_le_bytes())?;
+		Ok(())
+	}
+}
+
+/**
+ * Write a RIFF chunk with the given ID and data. (padded to even size)
+ *
+ * @param  buffer
+ * @param  id
+ * @param  data
+ */
+fn write_chunk(buffer: &mut Vec<u8>, id: &[u8; 4], data: &[u8]) {
+	buffer.extend(id);
+	buffer.extend((data.len() as u32).to_le_bytes());
+	buffer.extend(data);
+	if data.len() % 2 == 1 {
+		buffer.push(0);
+	}
+}
+
+/**
+ * Write a RIFF list with the given type and data.
+ *
+ * @param  buffer
+ * @param  list_type
+ * @param  data
+ */
+fn write_list(buffer: &mut Vec<u8>, list_type: &[u8; 4], data: &[u8]) {
+	buffer.extend(b"LIST");
+	buffer.extend((data.len() as u32 + 4).to_le_bytes());
+	buffer.extend(list_type);
+	buffer.extend(data);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::Rgba;
+	use pretty_assertions::assert_eq;
+	use std::io::Cursor;
+	/**
+	 * Read a little endian u32 from the data.
+	 *
+	 * @param  data
+	 * @param  offset
+	 * @return u32
+	 */
+	fn read_u32(data: &[u8], offset: u64) -> u32 {
+		let offset = offset as usize;
+		u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+	}
+	#[test]
+	fn test_avi_encoder() -> AppResult<()> {
+		let geometry = Geometry::new(0, 0, 4, 4);
+		let frames = [50, 100, 50]
+			.iter()
+			.enumerate()
+			.map(|(i, delay)| {
+				(
+					Image::new(
+						vec![Rgba::from([i as u8 * 100, 0, 255, 255]); 16],
+						false,
+						geometry,
+					),
+					Duration::from_millis(*delay),
+				)
+			})
+			.collect::<Vec<TimedFrame>>();
+		let mut output = Cursor::new(Vec::new());
+		AviEncoder::new(geometry, &mut output, 20, &JpgSettings::default())?
+			.stream(frames.clone().into_iter())?;
+		let data = output.into_inner();
+		assert_eq!(b"RIFF", &data[..4]);
+		assert_eq!(b"AVI ", &data[8..12]);
+		assert_eq!(data.len() as u32 - 8, read_u32(&data, RIFF_SIZE_OFFSET));
+		assert_eq!(b"MJPG", &data[112..116]);
+		assert_eq!(4, read_u32(&data, TOTAL_FRAMES_OFFSET));
+		assert_eq!(4, read_u32(&data, LENGTH_OFFSET));
+		assert_eq!(4, read_u32(&data, DMLH_FRAMES_OFFSET));
+		assert_eq!(1, read_u32(&data, SUPER_INDEX_OFFSET));
+		let movi = DMLH_FRAMES_OFFSET as usize + 256;
+		assert_eq!(b"movi", &data[movi..movi + 4]);
+		let idx1 = movi + read_u32(&data, movi as u64 - 4) as usize;
+		assert_eq!(b"idx1", &data[idx1..idx1 + 4]);
+		assert_eq!(4 * 16, read_u32(&data, idx1 as u64 + 4));
+		let frame = movi + read_u32(&data, idx1 as u64 + 16) as usize;
+		assert_eq!(b"00dc", &data[frame..frame + 4]);
+		assert_eq!([0xFF, 0xD8], data[frame + 8..frame + 10]);
+		assert_eq!(0, read_u32(&data, idx1 as u64 + 8 + 16 * 2 + 12));
+		let ix00 = read_u32(&data, SUPER_INDEX_ENTRIES_OFFSET) as usize;
+		assert_eq!(b"ix00", &data[ix00..ix00 + 4]);
+		assert_eq!(4, read_u32(&data, ix00 as u64 + 12));
+		assert_eq!(4, read_u32(&data, SUPER_INDEX_ENTRIES_OFFSET + 12));
+		let mut output = Cursor::new(Vec::new());
+		let mut encoder =
+			AviEncoder::new(geometry, &mut output, 20, &JpgSettings::default())?;
+		encoder.riff_limit = 0;
+		encoder.stream(frames.clone().into_iter())?;
+		let data = output.into_inner();
+		assert_eq!(1, read_u32(&data, TOTAL_FRAMES_OFFSET));
+		assert_eq!(4, read_u32(&data, LENGTH_OFFSET));
+		assert_eq!(4, read_u32(&data, DMLH_FRAMES_OFFSET));
+		assert_eq!(3, read_u32(&data, SUPER_INDEX_OFFSET));
+		let mut riff = 0;
+		for (i, duration) in [1, 2, 1].into_iter().enumerate() {
+			assert_eq!(
+				duration,
+				read_u32(&data, SUPER_INDEX_ENTRIES_OFFSET + 16 * i as u64 + 12)
+			);
+			riff += read_u32(&data, riff as u64 + RIFF_SIZE_OFFSET) as usize + 8;
+		}
+		assert_eq!(data.len(), riff);
+		let riff = read_u32(&data, RIFF_SIZE_OFFSET) as usize + 8;
+		assert_eq!(b"AVIX", &data[riff + 8..riff + 12]);
+		let mut output = Cursor::new(Vec::new());
+		AviEncoder::new(geometry, &mut output, 20, &JpgSettings::new(50))?
+			.save(FrameStore::from_frames(frames, 0)?, None)?;
+		assert_eq!(4, read_u32(&output.into_inner(), TOTAL_FRAMES_OFFSET));
+		let image = Image::new(
+			vec![Rgba::from([0, 0, 0, 255]); 4],
+			false,
+			Geometry::new(0, 0, 2, 2),
+		);
+		let mut output = Cursor::new(Vec::new());
+		assert!(AviEncoder::new(
+			geometry,
+			&mut output,
+			20,
+			&JpgSettings::default()
+		)?
+		.stream(vec![(image, Duration::ZERO)].into_iter())
+		.is_err());
+		let data = output.into_inner();
+		assert_eq!(data.len() as u32 - 8, read_u32(&data, RIFF_SIZE_OFFSET));
+		Ok(())
+	}
+}
@@ -10,6 +10,8 @@ pub enum FileFormat {
 	Any,
 	Gif,
 	Apng,
+	Av1,
+	Avi,
 	Png,
 	Jpg,
 	WebP,
@@ -37,6 +39,9 @@ impl FromStr for FileFormat {
 		match s {
 			"gif" => Ok(Self::Gif),
 			"apng" => Ok(Self::Apng),
+			#[cfg(feature = "av1")]
+			"av1" => Ok(Self::Av1),
+			"avi" => Ok(Self::Avi),
 			"png" => Ok(Self::Png),
 			"jpg" => Ok(Self::Jpg),
 			"webp" => Ok(Self::WebP),
@@ -83,6 +88,10 @@ impl FileFormat {
 					}
 				} else if matches.is_present("gif") {
 					Self::Gif
+				} else if matches.is_present("av1") {
+					Self::Av1
+				} else if matches.is_present("avi") {
+					Self::Avi
 				} else if matches.is_present("ff") {
 					Self::Ff
 				} else if matches.is_present("exr") {
@@ -107,6 +116,8 @@ impl FileFormat {
 					Self::Jpg
 				} else if matches.is_present("webp") {
 					Self::WebP
+				} else if matches.is_present("animate") {
+					Self::Gif
 				} else {
 					Self::Png
 				}
@@ -115,6 +126,10 @@ impl FileFormat {
 				if let Some(matches) = args.subcommand_matches("record") {
 					if matches.is_present("apng") {
 						Self::Apng
+					} else if matches.is_present("av1") {
+						Self::Av1
+					} else if matches.is_present("avi") {
+						Self::Avi
 					} else {
 						Self::Gif
 					}
@@ -151,7 +166,7 @@ impl FileFormat {
 		String::from(match self {
 			Self::Any => "output",
 			Self::Txt => "report",
-			Self::Gif | Self::Apng => "rec",
+			Self::Gif | Self::Apng | Self::Av1 | Self::Avi => "rec",
 			_ => "cap",
 		})
 	}
@@ -162,7 +177,7 @@ impl FileFormat {
 	 * @return bool
 	 */
 	pub fn is_animation(&self) -> bool {
-		self == &Self::Gif || self == &Self::Apng
+		matches!(self, Self::Gif | Self::Apng | Self::Av1 | Self::Avi)
 	}
 
 	/**
@@ -174,6 +189,7 @@ impl FileFormat {
 		match self {
 			Self::Any => String::from("*"),
 			Self::Pnm(v) => v.to_string(),
+			Self::Av1 => String::from("webm"),
 			_ => self.to_string(),
 		}
 		.to_lowercase()
@@ -58,6 +58,28 @@ impl File {
 		}
 	}
 
+	/**
+	 * Get the path with the given suffix appended to the file name.
+	 *
+	 * @param  path
+	 * @param  suffix
+	 * @return PathBuf
+	 */
+	pub fn get_path_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+		let mut file_name = path
+			.file_stem()
+			.and_then(OsStr::to_str)
+			.unwrap_or_default()
+			.to_string();
+		file_name.push('_');
+		file_name.push_str(suffix);
+		if let Some(extension) = path.extension().and_then(OsStr::to_str) {
+			file_name.push('.');
+			file_name.push_str(extension);
+		}
+		path.with_file_name(file_name)
+	}
+
 	/**
 	 * Get the default path for a file.
 	 *
@@ -139,6 +161,14 @@ mod tests {
 			)
 			.to_string()
 		);
+		assert_eq!(
+			PathBuf::from("/tmp/cap_DP-1.png"),
+			File::get_path_with_suffix(Path::new("/tmp/cap.png"), "DP-1")
+		);
+		assert_eq!(
+			PathBuf::from("rec_HDMI-1"),
+			File::get_path_with_suffix(Path::new("rec"), "HDMI-1")
+		);
 		for info in ["", "date", "timestamp"] {
 			let args = App::new("test")
 				.arg(Arg::with_name(info).long(&format!("--{info}")))
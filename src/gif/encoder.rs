@@ -1,14 +1,14 @@
 use crate::anim::settings::AnimSettings;
+use crate::anim::store::FrameStore;
+use crate::anim::TimedFrame;
 use crate::app::AppResult;
 use crate::image::geometry::Geometry;
-use crate::image::Image;
 use crate::util::state::InputState;
 use std::io::Write;
 
 /* GIF encoder configuration */
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderConfig<'a, Output: Write> {
-	pub fps: u32,
 	pub geometry: Geometry,
 	pub output: Output,
 	pub settings: &'a AnimSettings,
@@ -18,21 +18,17 @@ impl<'a, Output: Write> EncoderConfig<'a, Output> {
 	/**
 	 * Create a new EncoderConfig object.
 	 *
-	 * @param  fps
 	 * @param  geometry
 	 * @param  output
 	 * @param  settings
 	 * @return EncoderConfig
 	 */
 	pub fn new(
-		fps: u32,
 		geometry: Geometry,
 		output: Output,
 		settings: &'a AnimSettings,
 	) -> Self {
-		debug!("FPS: {}", fps);
 		Self {
-			fps,
 			geometry,
 			output,
 			settings,
@@ -47,9 +43,13 @@ pub trait Encoder<'a, Output: Write> {
 		Self: Sized;
 	fn save(
 		self,
-		images: Vec<Image>,
+		images: FrameStore,
 		input_state: Option<&'static InputState>,
 	) -> AppResult<()>;
+	fn stream<Frames: Iterator<Item = TimedFrame> + Send + 'static>(
+		self,
+		frames: Frames,
+	) -> AppResult<()>;
 }
 
 #[cfg(test)]
@@ -58,26 +58,69 @@ mod tests {
 	#[cfg(feature = "ski")]
 	use crate::gif::ski::GifskiEncoder;
 	use crate::gif::GifEncoder;
-	use image::Rgba;
+	use crate::image::Image;
+	use image::codecs::gif::GifDecoder;
+	use image::{AnimationDecoder, Rgba};
+	use std::time::Duration;
 	const GIF_HEADER: &[u8] = &[0x47, 0x49, 0x46, 0x38, 0x39, 0x61];
+	/* Output that fails to write */
+	struct ClosedOutput;
+	impl Write for ClosedOutput {
+		fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+			Err(std::io::ErrorKind::BrokenPipe.into())
+		}
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
 	fn get_config<Output: Write>(
 		output: Output,
 		settings: &AnimSettings,
-	) -> (EncoderConfig<'_, Output>, Vec<Image>) {
+	) -> (EncoderConfig<'_, Output>, Vec<TimedFrame>) {
 		let geometry = Geometry::new(0, 0, 1, 2);
 		let data = vec![Rgba::from([0, 0, 0, 0]), Rgba::from([255, 255, 255, 0])];
-		let images = vec![
-			Image::new(data.clone(), false, geometry),
-			Image::new(data.into_iter().rev().collect(), false, geometry),
+		let frames = vec![
+			(
+				Image::new(data.clone(), false, geometry),
+				Duration::from_millis(15),
+			),
+			(
+				Image::new(data.into_iter().rev().collect(), false, geometry),
+				Duration::from_millis(15),
+			),
 		];
-		(EncoderConfig::new(10, geometry, output, settings), images)
+		(EncoderConfig::new(geometry, output, settings), frames)
 	}
 	#[test]
 	fn test_gif_encoder() {
 		let mut output = Vec::new();
 		let settings = AnimSettings::default();
-		let (config, images) = get_config(&mut output, &settings);
-		GifEncoder::new(config).unwrap().save(images, None).unwrap();
+		let (config, frames) = get_config(&mut output, &settings);
+		GifEncoder::new(config)
+			.unwrap()
+			.save(
+				FrameStore::from_frames(frames.clone(), settings.memory_limit)
+					.unwrap(),
+				None,
+			)
+			.unwrap();
+		let mut stream = Vec::new();
+		let config =
+			EncoderConfig::new(Geometry::new(0, 0, 1, 2), &mut stream, &settings);
+		GifEncoder::new(config)
+			.unwrap()
+			.stream(frames.into_iter())
+			.unwrap();
+		assert_eq!(output, stream);
+		let delays = GifDecoder::new(output.as_slice())
+			.unwrap()
+			.into_frames()
+			.collect_frames()
+			.unwrap()
+			.iter()
+			.map(|frame| frame.delay().numer_denom_ms())
+			.collect::<Vec<(u32, u32)>>();
+		assert_eq!(vec![(20, 1), (10, 1)], delays);
 		output.truncate(6);
 		assert_eq!(GIF_HEADER, output);
 		output.clear();
@@ -87,12 +130,21 @@ mod tests {
 	fn test_gifski_encoder() {
 		let mut output = Vec::new();
 		let settings = AnimSettings::default();
-		let (config, images) = get_config(&mut output, &settings);
+		let (config, frames) = get_config(&mut output, &settings);
 		GifskiEncoder::new(config)
 			.unwrap()
-			.save(images, None)
+			.save(
+				FrameStore::from_frames(frames.clone(), settings.memory_limit)
+					.unwrap(),
+				None,
+			)
 			.unwrap();
 		output.truncate(6);
 		assert_eq!(GIF_HEADER, output);
+		let (config, _) = get_config(ClosedOutput, &settings);
+		assert!(GifskiEncoder::new(config)
+			.unwrap()
+			.stream(frames.into_iter())
+			.is_err());
 	}
 }
@@ -3,21 +3,54 @@ pub mod encoder;
 pub mod ski;
 
 use crate::anim::settings::AnimSettings;
-use crate::app::AppResult;
+use crate::anim::store::FrameStore;
+use crate::anim::TimedFrame;
+use crate::app::{AppError, AppResult};
 use crate::gif::encoder::{Encoder, EncoderConfig};
 use crate::image::Image;
 use crate::util::state::InputState;
 use gif::{Encoder as BaseEncoder, Frame, Repeat};
 use image::ExtendedColorType;
 use std::io::{self, Write};
+use std::time::Duration;
 
 /* GIF encoder and settings */
 pub struct GifEncoder<'a, Output: Write> {
-	fps: u32,
+	time: Duration,
 	encoder: BaseEncoder<Output>,
 	settings: &'a AnimSettings,
 }
 
+impl<'a, Output: Write> GifEncoder<'a, Output> {
+	/**
+	 * Encode the image as frame and write to the GIF file.
+	 *
+	 * @param  image
+	 * @param  delay
+	 * @return Result
+	 */
+	fn write_frame(&mut self, image: &Image, delay: Duration) -> AppResult<()> {
+		let speed = 30
+			- self.settings.map_range(
+				self.settings.quality.into(),
+				(1., 100.),
+				(0., 29.),
+			) as i32;
+		let mut frame = Frame::from_rgba_speed(
+			image.geometry.width.try_into().unwrap_or_default(),
+			image.geometry.height.try_into().unwrap_or_default(),
+			&mut image.get_data(ExtendedColorType::Rgba8),
+			speed,
+		);
+		let start = (self.time.as_secs_f64() * 1e2).round();
+		self.time += delay;
+		frame.delay = ((self.time.as_secs_f64() * 1e2).round() - start) as u16;
+		frame.make_lzw_pre_encoded();
+		self.encoder.write_lzw_pre_encoded_frame(&frame)?;
+		Ok(())
+	}
+}
+
 impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 	/**
 	 * Create a new GifEncoder object.
@@ -37,7 +70,7 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 			_ => Repeat::Infinite,
 		})?;
 		Ok(Self {
-			fps: config.fps,
+			time: Duration::ZERO,
 			encoder,
 			settings: config.settings,
 		})
@@ -52,16 +85,10 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 	 */
 	fn save(
 		mut self,
-		images: Vec<Image>,
+		images: FrameStore,
 		input_state: Option<&'static InputState>,
 	) -> AppResult<()> {
-		let speed = 30
-			- self.settings.map_range(
-				self.settings.quality.into(),
-				(1., 100.),
-				(0., 29.),
-			) as i32;
-		for (i, image) in images.iter().enumerate() {
+		for i in 0..images.len() {
 			let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
 			info!("Saving... ({:.1}%)\r", percentage);
 			debug!(
@@ -75,20 +102,30 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifEncoder<'a, Output> {
 				if state.check_cancel_keys() {
 					info!("\n");
 					warn!("User interrupt detected.");
-					panic!("Failed to write the frames")
+					return Err(AppError::FrameError(String::from(
+						"Failed to write the frames",
+					)));
 				}
 			}
-			let mut frame = Frame::from_rgba_speed(
-				image.geometry.width.try_into().unwrap_or_default(),
-				image.geometry.height.try_into().unwrap_or_default(),
-				&mut image.get_data(ExtendedColorType::Rgba8),
-				speed,
-			);
-			frame.delay = (1e2 / self.fps as f32) as u16;
-			frame.make_lzw_pre_encoded();
-			self.encoder.write_lzw_pre_encoded_frame(&frame)?;
+			self.write_frame(&images.get(i)?, images.get_delay(i))?;
 		}
 		info!("\n");
 		Ok(())
 	}
+
+	/**
+	 * Encode images as frame while they are received.
+	 *
+	 * @param  frames
+	 * @return Result
+	 */
+	fn stream<Frames: Iterator<Item = TimedFrame> + Send + 'static>(
+		mut self,
+		frames: Frames,
+	) -> AppResult<()> {
+		for (image, delay) in frames {
+			self.write_frame(&image, delay)?;
+		}
+		Ok(())
+	}
 }
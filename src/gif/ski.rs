@@ -1,14 +1,15 @@
-use crate::app::AppResult;
+use crate::anim::store::FrameStore;
+use crate::anim::TimedFrame;
+use crate::app::{AppError, AppResult};
 use crate::gif::encoder::{Encoder, EncoderConfig};
-use crate::image::Image;
 use crate::util::state::InputState;
 use gifski::{Collector, Repeat, Writer};
 use std::io::{self, Write};
 use std::thread;
+use std::time::Duration;
 
 /* GIF encoder and settings */
 pub struct GifskiEncoder<Output: Write> {
-	fps: u32,
 	collector: Collector,
 	writer: Writer,
 	output: Output,
@@ -33,7 +34,6 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifskiEncoder<Output> {
 			},
 		})?;
 		Ok(Self {
-			fps: config.fps,
 			collector,
 			writer,
 			output: config.output,
@@ -49,12 +49,12 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifskiEncoder<Output> {
 	 */
 	fn save(
 		self,
-		images: Vec<Image>,
+		images: FrameStore,
 		input_state: Option<&'static InputState>,
 	) -> AppResult<()> {
-		let fps = self.fps;
-		let collector_thread = thread::spawn(move || {
-			for (i, image) in images.iter().enumerate() {
+		let collector_thread = thread::spawn(move || -> AppResult<()> {
+			let mut time = Duration::ZERO;
+			for i in 0..images.len() {
 				let percentage = ((i + 1) as f64 / images.len() as f64) * 100.;
 				info!("Saving... ({:.1}%)\r", percentage);
 				debug!(
@@ -63,25 +63,64 @@ impl<'a, Output: Write> Encoder<'a, Output> for GifskiEncoder<Output> {
 					i + 1,
 					images.len()
 				);
-				io::stdout().flush().expect("Failed to flush stdout");
+				io::stdout().flush()?;
 				if let Some(state) = input_state {
 					if state.check_cancel_keys() {
 						info!("\n");
 						warn!("User interrupt detected.");
-						panic!("Failed to write the frames")
+						return Err(AppError::FrameError(String::from(
+							"Failed to write the frames",
+						)));
 					}
 				}
-				self.collector
-					.add_frame_rgba(i, image.get_img_vec(), i as f64 / fps as f64)
-					.expect("Failed to collect a frame");
+				let image = images.get(i)?;
+				self.collector.add_frame_rgba(
+					i,
+					image.get_img_vec(),
+					time.as_secs_f64(),
+				)?;
+				time += images.get_delay(i);
 			}
 			info!("\n");
+			Ok(())
 		});
-		self.writer
-			.write(self.output, &mut gifski::progress::NoProgress {})?;
+		let written = self
+			.writer
+			.write(self.output, &mut gifski::progress::NoProgress {});
 		collector_thread
 			.join()
-			.expect("Failed to collect the frames");
-		Ok(())
+			.expect("Failed to join the collector thread.")?;
+		Ok(written?)
+	}
+
+	/**
+	 * Encode images as frame while they are received.
+	 *
+	 * @param  frames
+	 * @return Result
+	 */
+	fn stream<Frames: Iterator<Item = TimedFrame> + Send + 'static>(
+		self,
+		frames: Frames,
+	) -> AppResult<()> {
+		let collector_thread = thread::spawn(move || -> AppResult<()> {
+			let mut time = Duration::ZERO;
+			for (i, (image, delay)) in frames.enumerate() {
+				self.collector.add_frame_rgba(
+					i,
+					image.get_img_vec(),
+					time.as_secs_f64(),
+				)?;
+				time += delay;
+			}
+			Ok(())
+		});
+		let written = self
+			.writer
+			.write(self.output, &mut gifski::progress::NoProgress {});
+		collector_thread
+			.join()
+			.expect("Failed to join the collector thread.")?;
+		Ok(written?)
 	}
 }
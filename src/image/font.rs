@@ -0,0 +1,193 @@
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use image::Rgba;
+
+/* Width of a glyph in pixels */
+const GLYPH_WIDTH: u32 = 5;
+/* Height of a glyph in pixels */
+const GLYPH_HEIGHT: u32 = 7;
+/* Space between the glyphs and the image border */
+const GLYPH_SPACING: u32 = 1;
+/* 5x7 bitmap font for printable ASCII characters (column-major, LSB on top) */
+const GLYPHS: [[u8; 5]; 95] = [
+	[0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+	[0x00, 0x00, 0x5F, 0x00, 0x00], // '!'
+	[0x00, 0x07, 0x00, 0x07, 0x00], // '"'
+	[0x14, 0x7F, 0x14, 0x7F, 0x14], // '#'
+	[0x24, 0x2A, 0x7F, 0x2A, 0x12], // '$'
+	[0x23, 0x13, 0x08, 0x64, 0x62], // '%'
+	[0x36, 0x49, 0x55, 0x22, 0x50], // '&'
+	[0x00, 0x05, 0x03, 0x00, 0x00], // '''
+	[0x00, 0x1C, 0x22, 0x41, 0x00], // '('
+	[0x00, 0x41, 0x22, 0x1C, 0x00], // ')'
+	[0x08, 0x2A, 0x1C, 0x2A, 0x08], // '*'
+	[0x08, 0x08, 0x3E, 0x08, 0x08], // '+'
+	[0x00, 0x50, 0x30, 0x00, 0x00], // ','
+	[0x08, 0x08, 0x08, 0x08, 0x08], // '-'
+	[0x00, 0x60, 0x60, 0x00, 0x00], // '.'
+	[0x20, 0x10, 0x08, 0x04, 0x02], // '/'
+	[0x3E, 0x51, 0x49, 0x45, 0x3E], // '0'
+	[0x00, 0x42, 0x7F, 0x40, 0x00], // '1'
+	[0x42, 0x61, 0x51, 0x49, 0x46], // '2'
+	[0x21, 0x41, 0x45, 0x4B, 0x31], // '3'
+	[0x18, 0x14, 0x12, 0x7F, 0x10], // '4'
+	[0x27, 0x45, 0x45, 0x45, 0x39], // '5'
+	[0x3C, 0x4A, 0x49, 0x49, 0x30], // '6'
+	[0x01, 0x71, 0x09, 0x05, 0x03], // '7'
+	[0x36, 0x49, 0x49, 0x49, 0x36], // '8'
+	[0x06, 0x49, 0x49, 0x29, 0x1E], // '9'
+	[0x00, 0x36, 0x36, 0x00, 0x00], // ':'
+	[0x00, 0x56, 0x36, 0x00, 0x00], // ';'
+	[0x00, 0x08, 0x14, 0x22, 0x41], // '<'
+	[0x14, 0x14, 0x14, 0x14, 0x14], // '='
+	[0x41, 0x22, 0x14, 0x08, 0x00], // '>'
+	[0x02, 0x01, 0x51, 0x09, 0x06], // '?'
+	[0x32, 0x49, 0x79, 0x41, 0x3E], // '@'
+	[0x7E, 0x11, 0x11, 0x11, 0x7E], // 'A'
+	[0x7F, 0x49, 0x49, 0x49, 0x36], // 'B'
+	[0x3E, 0x41, 0x41, 0x41, 0x22], // 'C'
+	[0x7F, 0x41, 0x41, 0x22, 0x1C], // 'D'
+	[0x7F, 0x49, 0x49, 0x49, 0x41], // 'E'
+	[0x7F, 0x09, 0x09, 0x01, 0x01], // 'F'
+	[0x3E, 0x41, 0x41, 0x51, 0x32], // 'G'
+	[0x7F, 0x08, 0x08, 0x08, 0x7F], // 'H'
+	[0x00, 0x41, 0x7F, 0x41, 0x00], // 'I'
+	[0x20, 0x40, 0x41, 0x3F, 0x01], // 'J'
+	[0x7F, 0x08, 0x14, 0x22, 0x41], // 'K'
+	[0x7F, 0x40, 0x40, 0x40, 0x40], // 'L'
+	[0x7F, 0x02, 0x04, 0x02, 0x7F], // 'M'
+	[0x7F, 0x04, 0x08, 0x10, 0x7F], // 'N'
+	[0x3E, 0x41, 0x41, 0x41, 0x3E], // 'O'
+	[0x7F, 0x09, 0x09, 0x09, 0x06], // 'P'
+	[0x3E, 0x41, 0x51, 0x21, 0x5E], // 'Q'
+	[0x7F, 0x09, 0x19, 0x29, 0x46], // 'R'
+	[0x46, 0x49, 0x49, 0x49, 0x31], // 'S'
+	[0x01, 0x01, 0x7F, 0x01, 0x01], // 'T'
+	[0x3F, 0x40, 0x40, 0x40, 0x3F], // 'U'
+	[0x1F, 0x20, 0x40, 0x20, 0x1F], // 'V'
+	[0x7F, 0x20, 0x18, 0x20, 0x7F], // 'W'
+	[0x63, 0x14, 0x08, 0x14, 0x63], // 'X'
+	[0x03, 0x04, 0x78, 0x04, 0x03], // 'Y'
+	[0x61, 0x51, 0x49, 0x45, 0x43], // 'Z'
+	[0x00, 0x00, 0x7F, 0x41, 0x41], // '['
+	[0x02, 0x04, 0x08, 0x10, 0x20], // '\'
+	[0x41, 0x41, 0x7F, 0x00, 0x00], // ']'
+	[0x04, 0x02, 0x01, 0x02, 0x04], // '^'
+	[0x40, 0x40, 0x40, 0x40, 0x40], // '_'
+	[0x00, 0x01, 0x02, 0x04, 0x00], // '`'
+	[0x20, 0x54, 0x54, 0x54, 0x78], // 'a'
+	[0x7F, 0x48, 0x44, 0x44, 0x38], // 'b'
+	[0x38, 0x44, 0x44, 0x44, 0x20], // 'c'
+	[0x38, 0x44, 0x44, 0x48, 0x7F], // 'd'
+	[0x38, 0x54, 0x54, 0x54, 0x18], // 'e'
+	[0x08, 0x7E, 0x09, 0x01, 0x02], // 'f'
+	[0x08, 0x14, 0x54, 0x54, 0x3C], // 'g'
+	[0x7F, 0x08, 0x04, 0x04, 0x78], // 'h'
+	[0x00, 0x44, 0x7D, 0x40, 0x00], // 'i'
+	[0x20, 0x40, 0x44, 0x3D, 0x00], // 'j'
+	[0x00, 0x7F, 0x10, 0x28, 0x44], // 'k'
+	[0x00, 0x41, 0x7F, 0x40, 0x00], // 'l'
+	[0x7C, 0x04, 0x18, 0x04, 0x78], // 'm'
+	[0x7C, 0x08, 0x04, 0x04, 0x78], // 'n'
+	[0x38, 0x44, 0x44, 0x44, 0x38], // 'o'
+	[0x7C, 0x14, 0x14, 0x14, 0x08], // 'p'
+	[0x08, 0x14, 0x14, 0x18, 0x7C], // 'q'
+	[0x7C, 0x08, 0x04, 0x04, 0x08], // 'r'
+	[0x48, 0x54, 0x54, 0x54, 0x20], // 's'
+	[0x04, 0x3F, 0x44, 0x40, 0x20], // 't'
+	[0x3C, 0x40, 0x40, 0x20, 0x7C], // 'u'
+	[0x1C, 0x20, 0x40, 0x20, 0x1C], // 'v'
+	[0x3C, 0x40, 0x30, 0x40, 0x3C], // 'w'
+	[0x44, 0x28, 0x10, 0x28, 0x44], // 'x'
+	[0x0C, 0x50, 0x50, 0x50, 0x3C], // 'y'
+	[0x44, 0x64, 0x54, 0x4C, 0x44], // 'z'
+	[0x00, 0x08, 0x36, 0x41, 0x00], // '{'
+	[0x00, 0x00, 0x7F, 0x00, 0x00], // '|'
+	[0x00, 0x41, 0x36, 0x08, 0x00], // '}'
+	[0x08, 0x08, 0x2A, 0x1C, 0x08], // '~'
+];
+
+/**
+ * Get the glyph of the given character.
+ *
+ * @param  c
+ * @return Array of u8
+ */
+fn get_glyph(c: char) -> [u8; 5] {
+	GLYPHS
+		.get((c as usize).wrapping_sub(0x20))
+		.copied()
+		.unwrap_or(GLYPHS[usize::from(b'?' - 0x20)])
+}
+
+/**
+ * Render the given text into an image.
+ *
+ * @param  text
+ * @param  scale
+ * @param  foreground
+ * @param  background
+ * @return Image
+ */
+pub fn render_text(
+	text: &str,
+	scale: u32,
+	foreground: Rgba<u8>,
+	background: Rgba<u8>,
+) -> Image {
+	let scale = scale.max(1);
+	let glyphs = text.chars().map(get_glyph).collect::<Vec<[u8; 5]>>();
+	let count = u32::try_from(glyphs.len()).unwrap_or_default();
+	let (width, height) = (
+		(count * (GLYPH_WIDTH + GLYPH_SPACING) + GLYPH_SPACING) * scale,
+		(GLYPH_HEIGHT + GLYPH_SPACING * 2) * scale,
+	);
+	let mut data = vec![background; (width * height) as usize];
+	for (i, glyph) in glyphs.iter().enumerate() {
+		let left = (u32::try_from(i).unwrap_or_default()
+			* (GLYPH_WIDTH + GLYPH_SPACING)
+			+ GLYPH_SPACING)
+			* scale;
+		for (column, bits) in glyph.iter().enumerate() {
+			for row in 0..GLYPH_HEIGHT {
+				if bits & (1 << row) == 0 {
+					continue;
+				}
+				let x = left + u32::try_from(column).unwrap_or_default() * scale;
+				let y = (row + GLYPH_SPACING) * scale;
+				for dy in 0..scale {
+					for dx in 0..scale {
+						data[((y + dy) * width + x + dx) as usize] = foreground;
+					}
+				}
+			}
+		}
+	}
+	Image::new(data, true, Geometry::new(0, 0, width, height))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::ExtendedColorType;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_font() {
+		assert_eq!(GLYPHS[usize::from(b'?' - 0x20)], get_glyph('\u{2318}'));
+		assert_eq!([0x7F, 0x08, 0x08, 0x08, 0x7F], get_glyph('H'));
+		let (foreground, background) =
+			(Rgba::from([255, 255, 255, 255]), Rgba::from([0, 0, 0, 0]));
+		let image = render_text("-", 2, foreground, background);
+		assert_eq!(Geometry::new(0, 0, 14, 18), image.geometry);
+		let data = image.get_data(ExtendedColorType::L8);
+		assert_eq!(
+			vec![0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0],
+			data[14 * 8..14 * 9].to_vec()
+		);
+		assert_eq!(vec![0; 14], data[..14].to_vec());
+		assert_eq!(
+			Geometry::new(0, 0, 13, 9),
+			render_text("ab", 1, foreground, background).geometry
+		);
+	}
+}
@@ -1,10 +1,13 @@
+pub mod font;
 pub mod geometry;
 pub mod padding;
+pub mod qoi;
 pub mod settings;
 use std::fmt;
 
 use crate::image::geometry::Geometry;
-use image::{ExtendedColorType, Rgba};
+use image::imageops::{self, FilterType};
+use image::{ExtendedColorType, ImageBuffer, Rgba};
 #[cfg(feature = "ski")]
 use {
 	imgref::{Img, ImgVec},
@@ -93,6 +96,172 @@ impl Image {
 		})
 	}
 
+	/**
+	 * Blend the given image on top of the image.
+	 *
+	 * @param  overlay
+	 * @param  x
+	 * @param  y
+	 */
+	pub fn blend(&mut self, overlay: &Image, x: i32, y: i32) {
+		let (width, height) = (
+			i64::from(self.geometry.width),
+			i64::from(self.geometry.height),
+		);
+		let overlay_width = i64::from(overlay.geometry.width.max(1));
+		for (i, pixel) in overlay.data.iter().enumerate() {
+			let i = i64::try_from(i).unwrap_or_default();
+			let (px, py) = (
+				i64::from(x) + i % overlay_width,
+				i64::from(y) + i / overlay_width,
+			);
+			if px < 0 || py < 0 || px >= width || py >= height {
+				continue;
+			}
+			let alpha = u32::from(pixel[3]);
+			let index = usize::try_from(py * width + px).unwrap_or_default();
+			if let Some(base) = self.data.get_mut(index) {
+				for c in 0..3 {
+					base[c] = ((u32::from(pixel[c]) * alpha
+						+ u32::from(base[c]) * (255 - alpha))
+						/ 255) as u8;
+				}
+				base[3] = base[3].max(pixel[3]);
+			}
+		}
+	}
+
+	/**
+	 * Get the part of the image inside the given geometry.
+	 *
+	 * @param  geometry
+	 * @return Image
+	 */
+	pub fn crop(&self, geometry: Geometry) -> Image {
+		let (left, top) = (
+			i64::from(geometry.x) - i64::from(self.geometry.x),
+			i64::from(geometry.y) - i64::from(self.geometry.y),
+		);
+		let (x, y) = (left.max(0), top.max(0));
+		let right =
+			(left + i64::from(geometry.width)).min(i64::from(self.geometry.width));
+		let bottom =
+			(top + i64::from(geometry.height)).min(i64::from(self.geometry.height));
+		let (width, height) = ((right - x).max(0), (bottom - y).max(0));
+		let mut data = Vec::new();
+		for row in y..(y + height) {
+			let start = usize::try_from(row * i64::from(self.geometry.width) + x)
+				.unwrap_or_default();
+			if let Some(pixels) = self
+				.data
+				.get(start..start + usize::try_from(width).unwrap_or_default())
+			{
+				data.extend_from_slice(pixels);
+			}
+		}
+		Image::new(
+			data,
+			self.alpha_channel,
+			Geometry::new(
+				geometry.x.max(self.geometry.x),
+				geometry.y.max(self.geometry.y),
+				u32::try_from(width).unwrap_or_default(),
+				u32::try_from(height).unwrap_or_default(),
+			),
+		)
+	}
+
+	/**
+	 * Scale the image to the given size.
+	 *
+	 * @param  width
+	 * @param  height
+	 * @return Image
+	 */
+	pub fn resize(&self, width: u32, height: u32) -> Image {
+		match ImageBuffer::<Rgba<u8>, Vec<u8>>::from_raw(
+			self.geometry.width,
+			self.geometry.height,
+			self.data.iter().flat_map(|rgba| rgba.0).collect(),
+		) {
+			Some(buffer) => Image::new(
+				imageops::resize(&buffer, width, height, FilterType::Triangle)
+					.pixels()
+					.copied()
+					.collect(),
+				self.alpha_channel,
+				Geometry::new(self.geometry.x, self.geometry.y, width, height),
+			),
+			None => self.clone(),
+		}
+	}
+
+	/**
+	 * Create a new Image object from the raw RGBA data.
+	 *
+	 * @param  data
+	 * @param  alpha_channel
+	 * @param  geometry
+	 * @return Image
+	 */
+	pub fn from_raw_data(
+		data: &[u8],
+		alpha_channel: bool,
+		geometry: Geometry,
+	) -> Self {
+		Self::new(
+			data.chunks_exact(4)
+				.map(|rgba| Rgba::from([rgba[0], rgba[1], rgba[2], rgba[3]]))
+				.collect(),
+			alpha_channel,
+			geometry,
+		)
+	}
+
+	/**
+	 * Get the raw RGBA data of the image.
+	 *
+	 * @return Vector of u8
+	 */
+	pub fn get_raw_data(&self) -> Vec<u8> {
+		self.data.iter().flat_map(|rgba| rgba.0).collect()
+	}
+
+	/**
+	 * Check if the image has an alpha channel.
+	 *
+	 * @return bool
+	 */
+	pub fn has_alpha(&self) -> bool {
+		self.alpha_channel
+	}
+
+	/**
+	 * Check if the image differs from the given image in at most
+	 * the given number of pixels.
+	 *
+	 * @param  image
+	 * @param  threshold
+	 * @return bool
+	 */
+	pub fn is_similar(&self, image: &Image, threshold: u32) -> bool {
+		if (self.geometry.width, self.geometry.height)
+			!= (image.geometry.width, image.geometry.height)
+		{
+			return false;
+		}
+		let mut diff = 0;
+		for (a, b) in self.data.iter().zip(image.data.iter()) {
+			if a != b {
+				diff += 1;
+				if diff > threshold {
+					return false;
+				}
+			}
+		}
+		true
+	}
+
 	/**
 	 * Get an Img Vector from the image data.
 	 *
@@ -146,5 +315,71 @@ mod tests {
 		assert_eq!(255, image.get_data(ExtendedColorType::Rgb8)[4]);
 		assert_eq!(255, image.get_data(ExtendedColorType::Rgba8)[5]);
 		assert_eq!(128, image.get_data(ExtendedColorType::Rgba16)[5]);
+		let mut image = Image::new(data.to_vec(), true, Geometry::new(0, 0, 2, 1));
+		image.blend(
+			&Image::new(
+				vec![Rgba::from([0, 0, 0, 255])],
+				true,
+				Geometry::new(0, 0, 1, 1),
+			),
+			1,
+			0,
+		);
+		assert_eq!(
+			vec![128, 128, 128, 0, 0, 0, 0, 255],
+			image.get_data(ExtendedColorType::Rgba8)
+		);
+		image.blend(
+			&Image::new(
+				vec![Rgba::from([255, 255, 255, 255])],
+				true,
+				Geometry::new(0, 0, 1, 1),
+			),
+			-1,
+			0,
+		);
+		assert_eq!(128, image.get_data(ExtendedColorType::Rgba8)[0]);
+		let image = Image::new(
+			(0..6).map(|v| Rgba::from([v, v, v, 255])).collect(),
+			false,
+			Geometry::new(10, 10, 3, 2),
+		);
+		let raw_data = image.get_raw_data();
+		assert_eq!(24, raw_data.len());
+		assert_eq!(
+			image.get_data(ExtendedColorType::Rgba8),
+			Image::from_raw_data(&raw_data, image.has_alpha(), image.geometry)
+				.get_data(ExtendedColorType::Rgba8)
+		);
+		let mut changed = image.clone();
+		changed.data[0] = Rgba::from([255, 0, 0, 255]);
+		changed.data[5] = Rgba::from([0, 255, 0, 255]);
+		assert!(image.is_similar(&image, 0));
+		assert!(!image.is_similar(&changed, 1));
+		assert!(image.is_similar(&changed, 2));
+		assert!(!image.is_similar(&image.resize(6, 4), 100));
+		let cropped = image.crop(Geometry::new(11, 9, 5, 5));
+		assert_eq!(Geometry::new(11, 10, 2, 2), cropped.geometry);
+		assert_eq!(vec![1, 2, 4, 5], cropped.get_data(ExtendedColorType::L8));
+		assert_eq!(
+			0,
+			image
+				.crop(Geometry::new(0, 0, 5, 5))
+				.get_data(ExtendedColorType::L8)
+				.len()
+		);
+		let resized = image.resize(6, 4);
+		assert_eq!(Geometry::new(10, 10, 6, 4), resized.geometry);
+		assert_eq!(24, resized.get_data(ExtendedColorType::L8).len());
+		assert_eq!(
+			vec![255; 4],
+			Image::new(
+				vec![Rgba::from([255, 255, 255, 255]); 4],
+				false,
+				Geometry::new(0, 0, 2, 2),
+			)
+			.resize(1, 4)
+			.get_data(ExtendedColorType::L8)
+		);
 	}
 }
@@ -0,0 +1,157 @@
+use image::error::{
+	ImageError, ImageFormatHint, ImageResult, UnsupportedError, UnsupportedErrorKind,
+};
+use image::{ColorType, ImageEncoder};
+use std::io::Write;
+
+/* Magic bytes of the QOI header */
+const QOI_MAGIC: &[u8; 4] = b"qoif";
+/* Bytes that mark the end of the QOI stream */
+const QOI_PADDING: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+/* Maximum length of a run of identical pixels */
+const MAX_RUN: u8 = 62;
+
+/* Encoder for the "Quite OK Image" format */
+pub struct QoiEncoder<Output: Write> {
+	output: Output,
+}
+
+impl<Output: Write> QoiEncoder<Output> {
+	/**
+	 * Create a new QoiEncoder object.
+	 *
+	 * @param  output
+	 * @return QoiEncoder
+	 */
+	pub fn new(output: Output) -> Self {
+		Self { output }
+	}
+
+	/**
+	 * Encode the pixels to QOI chunks.
+	 *
+	 * @param  pixels
+	 * @return Vector of u8
+	 */
+	fn encode<Pixels: ExactSizeIterator<Item = [u8; 4]>>(pixels: Pixels) -> Vec<u8> {
+		let mut data = Vec::new();
+		let mut index = [[0; 4]; 64];
+		let mut prev = [0, 0, 0, 255];
+		let mut run = 0;
+		let pixel_count = pixels.len();
+		for (i, pixel) in pixels.enumerate() {
+			if pixel == prev {
+				run += 1;
+				if run == MAX_RUN || i == pixel_count - 1 {
+					data.push(0xc0 | (run - 1));
+					run = 0;
+				}
+				continue;
+			}
+			if run > 0 {
+				data.push(0xc0 | (run - 1));
+				run = 0;
+			}
+			let [r, g, b, a] = pixel;
+			let hash = (usize::from(r) * 3
+				+ usize::from(g) * 5
+				+ usize::from(b) * 7
+				+ usize::from(a) * 11)
+				% 64;
+			if index[hash] == pixel {
+				data.push(hash as u8);
+			} else if a == prev[3] {
+				index[hash] = pixel;
+				let vr = r.wrapping_sub(prev[0]) as i8;
+				let vg = g.wrapping_sub(prev[1]) as i8;
+				let vb = b.wrapping_sub(prev[2]) as i8;
+				let (vg_r, vg_b) = (vr.wrapping_sub(vg), vb.wrapping_sub(vg));
+				if [vr, vg, vb].iter().all(|v| (-2..=1).contains(v)) {
+					data.push(
+						0x40 | ((vr + 2) as u8) << 4
+							| ((vg + 2) as u8) << 2
+							| (vb + 2) as u8,
+					);
+				} else if (-8..=7).contains(&vg_r)
+					&& (-32..=31).contains(&vg)
+					&& (-8..=7).contains(&vg_b)
+				{
+					data.push(0x80 | (vg + 32) as u8);
+					data.push(((vg_r + 8) as u8) << 4 | (vg_b + 8) as u8);
+				} else {
+					data.extend([0xfe, r, g, b]);
+				}
+			} else {
+				index[hash] = pixel;
+				data.extend([0xff, r, g, b, a]);
+			}
+			prev = pixel;
+		}
+		data
+	}
+}
+
+/* ImageEncoder implementation for writing QOI images */
+impl<Output: Write> ImageEncoder for QoiEncoder<Output> {
+	fn write_image(
+		mut self,
+		buf: &[u8],
+		width: u32,
+		height: u32,
+		color_type: ColorType,
+	) -> ImageResult<()> {
+		let channels = match color_type {
+			ColorType::Rgb8 => 3,
+			ColorType::Rgba8 => 4,
+			_ => {
+				return Err(ImageError::Unsupported(
+					UnsupportedError::from_format_and_kind(
+						ImageFormatHint::Name(String::from("QOI")),
+						UnsupportedErrorKind::Color(color_type.into()),
+					),
+				))
+			}
+		};
+		let data =
+			Self::encode(buf.chunks_exact(usize::from(channels)).map(|pixel| {
+				[pixel[0], pixel[1], pixel[2], *pixel.get(3).unwrap_or(&255)]
+			}));
+		self.output.write_all(QOI_MAGIC)?;
+		self.output.write_all(&width.to_be_bytes())?;
+		self.output.write_all(&height.to_be_bytes())?;
+		self.output.write_all(&[channels, 0])?;
+		self.output.write_all(&data)?;
+		self.output.write_all(&QOI_PADDING)?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_qoi_encoder() -> ImageResult<()> {
+		let mut output = Vec::new();
+		QoiEncoder::new(&mut output).write_image(
+			&[
+				255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 20, 30, 255, 0,
+				0,
+			],
+			7,
+			1,
+			ColorType::Rgb8,
+		)?;
+		assert_eq!(b"qoif", &output[..4]);
+		assert_eq!([0, 0, 0, 7, 0, 0, 0, 1, 3, 0], output[4..14]);
+		assert_eq!(
+			vec![0x5a, 0xc0, 0x7a, 0xc1, 0xfe, 10, 20, 30, 0x32],
+			output[14..output.len() - 8]
+		);
+		assert_eq!(QOI_PADDING, output[output.len() - 8..]);
+		assert!(QoiEncoder::new(Vec::new())
+			.write_image(&[0], 1, 1, ColorType::L8)
+			.is_err());
+		Ok(())
+	}
+}
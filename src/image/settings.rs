@@ -1,5 +1,6 @@
 use crate::args::matches::ArgMatches;
 use crate::args::parser::ArgParser;
+use crate::file::format::FileFormat;
 use image::codecs::png::{CompressionType, FilterType};
 use image::codecs::pnm::{PnmSubtype, SampleEncoding};
 use image::codecs::webp::WebPQuality;
@@ -103,10 +104,18 @@ impl JpgSettings {
 	 * Create a new JpgSettings object from arguments.
 	 *
 	 * @param  matches
+	 * @param  file_format
 	 * @return JpgSettings
 	 */
-	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
-		Self::from_parser(ArgParser::from_subcommand(matches, "jpg"))
+	pub fn from_args(matches: &ArgMatches<'_>, file_format: &FileFormat) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(
+			matches,
+			match file_format {
+				FileFormat::Avi if matches.is_present("make") => "make",
+				FileFormat::Avi => "avi",
+				_ => "jpg",
+			},
+		))
 	}
 
 	/**
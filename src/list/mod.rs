@@ -0,0 +1,304 @@
+pub mod settings;
+
+use crate::image::geometry::Geometry;
+use crate::list::settings::{ListFormat, ListSettings};
+use std::fmt::Write;
+
+/* Properties of a top-level window */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WindowInfo {
+	pub id: u64,
+	pub title: Option<String>,
+	pub class: Option<String>,
+	pub pid: Option<u32>,
+	pub geometry: Geometry,
+	pub monitor: Option<String>,
+}
+
+/* Properties of a monitor (output) */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MonitorInfo {
+	pub name: String,
+	pub geometry: Geometry,
+	pub rotation: u16,
+	pub primary: bool,
+}
+
+impl MonitorInfo {
+	/**
+	 * Check if the monitor contains the center of the given geometry.
+	 *
+	 * @param  geometry
+	 * @return bool
+	 */
+	pub fn contains(&self, geometry: Geometry) -> bool {
+		let x = i64::from(geometry.x) + i64::from(geometry.width / 2);
+		let y = i64::from(geometry.y) + i64::from(geometry.height / 2);
+		x >= i64::from(self.geometry.x)
+			&& x < i64::from(self.geometry.x) + i64::from(self.geometry.width)
+			&& y >= i64::from(self.geometry.y)
+			&& y < i64::from(self.geometry.y) + i64::from(self.geometry.height)
+	}
+}
+
+/* List of windows and monitors */
+#[derive(Debug)]
+pub struct WindowList<'a> {
+	windows: Vec<WindowInfo>,
+	monitors: Vec<MonitorInfo>,
+	settings: &'a ListSettings,
+}
+
+impl<'a> WindowList<'a> {
+	/**
+	 * Create a new WindowList object.
+	 *
+	 * @param  windows
+	 * @param  monitors
+	 * @param  settings
+	 * @return WindowList
+	 */
+	pub fn new(
+		windows: Vec<WindowInfo>,
+		monitors: Vec<MonitorInfo>,
+		settings: &'a ListSettings,
+	) -> Self {
+		Self {
+			windows: if settings.windows {
+				windows
+			} else {
+				Vec::new()
+			},
+			monitors: if settings.monitors {
+				monitors
+			} else {
+				Vec::new()
+			},
+			settings,
+		}
+	}
+
+	/**
+	 * Get the list in the configured format.
+	 *
+	 * @return String
+	 */
+	pub fn get_output(&self) -> String {
+		match self.settings.format {
+			ListFormat::Table => self.get_table(),
+			ListFormat::Json => self.get_json(),
+		}
+	}
+
+	/**
+	 * Get the list as aligned text tables.
+	 *
+	 * @return String
+	 */
+	fn get_table(&self) -> String {
+		let mut tables = Vec::new();
+		if self.settings.windows {
+			tables.push(Self::format_table(
+				&["ID", "TITLE", "CLASS", "PID", "GEOMETRY", "MONITOR"],
+				self.windows
+					.iter()
+					.map(|window| {
+						vec![
+							format!("{:#x}", window.id),
+							window.title.clone().unwrap_or_default(),
+							window.class.clone().unwrap_or_default(),
+							window.pid.map(|v| v.to_string()).unwrap_or_default(),
+							Self::format_geometry(window.geometry),
+							window.monitor.clone().unwrap_or_default(),
+						]
+					})
+					.collect(),
+			));
+		}
+		if self.settings.monitors {
+			tables.push(Self::format_table(
+				&["NAME", "GEOMETRY", "ROTATION", "PRIMARY"],
+				self.monitors
+					.iter()
+					.map(|monitor| {
+						vec![
+							monitor.name.clone(),
+							Self::format_geometry(monitor.geometry),
+							monitor.rotation.to_string(),
+							monitor.primary.to_string(),
+						]
+					})
+					.collect(),
+			));
+		}
+		tables.join("\n")
+	}
+
+	/**
+	 * Format the given header and rows as a table.
+	 *
+	 * @param  header
+	 * @param  rows
+	 * @return String
+	 */
+	fn format_table(header: &[&str], rows: Vec<Vec<String>>) -> String {
+		let mut widths = header
+			.iter()
+			.map(|v| v.chars().count())
+			.collect::<Vec<usize>>();
+		for row in &rows {
+			for (width, value) in widths.iter_mut().zip(row) {
+				*width = (*width).max(value.chars().count());
+			}
+		}
+		let mut table = String::new();
+		let header = header.iter().map(|v| v.to_string()).collect();
+		for row in [header].iter().chain(&rows) {
+			let line = row
+				.iter()
+				.zip(&widths)
+				.map(|(value, width)| format!("{value:width$}"))
+				.collect::<Vec<String>>()
+				.join("  ");
+			let _ = writeln!(table, "{}", line.trim_end());
+		}
+		table
+	}
+
+	/**
+	 * Format the geometry with its position.
+	 *
+	 * @param  geometry
+	 * @return String
+	 */
+	fn format_geometry(geometry: Geometry) -> String {
+		format!("{}+{}+{}", geometry, geometry.x, geometry.y)
+	}
+
+	/**
+	 * Get the list as a JSON object.
+	 *
+	 * @return String
+	 */
+	fn get_json(&self) -> String {
+		let geometry = |geometry: Geometry| {
+			format!(
+				"{{\"x\":{},\"y\":{},\"width\":{},\"height\":{}}}",
+				geometry.x, geometry.y, geometry.width, geometry.height
+			)
+		};
+		let windows = self
+			.windows
+			.iter()
+			.map(|window| {
+				format!(
+					"{{\"id\":{},\"title\":{},\"class\":{},\"pid\":{},\"geometry\":{},\"monitor\":{}}}",
+					window.id,
+					Self::format_json_string(window.title.as_deref()),
+					Self::format_json_string(window.class.as_deref()),
+					window.pid.map_or(String::from("null"), |v| v.to_string()),
+					geometry(window.geometry),
+					Self::format_json_string(window.monitor.as_deref()),
+				)
+			})
+			.collect::<Vec<String>>()
+			.join(",");
+		let monitors = self
+			.monitors
+			.iter()
+			.map(|monitor| {
+				format!(
+					"{{\"name\":{},\"geometry\":{},\"rotation\":{},\"primary\":{}}}",
+					Self::format_json_string(Some(&monitor.name)),
+					geometry(monitor.geometry),
+					monitor.rotation,
+					monitor.primary,
+				)
+			})
+			.collect::<Vec<String>>()
+			.join(",");
+		format!("{{\"windows\":[{windows}],\"monitors\":[{monitors}]}}\n")
+	}
+
+	/**
+	 * Format the given value as a JSON string.
+	 *
+	 * @param  value (Option)
+	 * @return String
+	 */
+	fn format_json_string(value: Option<&str>) -> String {
+		match value {
+			Some(value) => {
+				let mut json = String::from("\"");
+				for c in value.chars() {
+					match c {
+						'"' => json.push_str("\\\""),
+						'\\' => json.push_str("\\\\"),
+						'\n' => json.push_str("\\n"),
+						'\r' => json.push_str("\\r"),
+						'\t' => json.push_str("\\t"),
+						c if c.is_control() => {
+							let _ = write!(json, "\\u{:04x}", c as u32);
+						}
+						c => json.push(c),
+					}
+				}
+				json.push('"');
+				json
+			}
+			None => String::from("null"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_list() {
+		let windows = vec![
+			WindowInfo {
+				id: 0x1e0_0003,
+				title: Some(String::from("menyoki \"test\"")),
+				class: Some(String::from("Alacritty")),
+				pid: Some(42),
+				geometry: Geometry::new(10, 20, 640, 480),
+				monitor: Some(String::from("HDMI-1")),
+			},
+			WindowInfo::default(),
+		];
+		let monitors = vec![MonitorInfo {
+			name: String::from("HDMI-1"),
+			geometry: Geometry::new(0, 0, 1920, 1080),
+			rotation: 0,
+			primary: true,
+		}];
+		assert!(monitors[0].contains(windows[0].geometry));
+		assert!(!monitors[0].contains(Geometry::new(1900, 0, 100, 100)));
+		let settings = ListSettings::default();
+		let list = WindowList::new(windows.clone(), monitors.clone(), &settings);
+		assert_eq!(
+			"ID         TITLE           CLASS      PID  GEOMETRY       MONITOR\n\
+			0x1e00003  menyoki \"test\"  Alacritty  42   640x480+10+20  HDMI-1\n\
+			0x0                                        0x0+0+0\n\
+			\n\
+			NAME    GEOMETRY       ROTATION  PRIMARY\n\
+			HDMI-1  1920x1080+0+0  0         true\n",
+			list.get_output()
+		);
+		let settings = ListSettings::new(ListFormat::Json, false, true);
+		let list = WindowList::new(windows, monitors, &settings);
+		assert_eq!(
+			"{\"windows\":[],\"monitors\":[{\"name\":\"HDMI-1\",\
+			\"geometry\":{\"x\":0,\"y\":0,\"width\":1920,\"height\":1080},\
+			\"rotation\":0,\"primary\":true}]}\n",
+			list.get_output()
+		);
+		assert_eq!(
+			"\"a\\\"b\\\\c\\n\\u0001\"",
+			WindowList::format_json_string(Some("a\"b\\c\n\u{1}"))
+		);
+		assert_eq!("null", WindowList::format_json_string(None));
+	}
+}
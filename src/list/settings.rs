@@ -0,0 +1,105 @@
+use crate::args::matches::ArgMatches;
+use crate::args::parser::ArgParser;
+
+/* Output format of the list */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListFormat {
+	Table,
+	Json,
+}
+
+/* Window and monitor listing settings */
+#[derive(Debug)]
+pub struct ListSettings {
+	pub format: ListFormat,
+	pub windows: bool,
+	pub monitors: bool,
+}
+
+/* Default initialization values for ListSettings */
+impl Default for ListSettings {
+	fn default() -> Self {
+		Self {
+			format: ListFormat::Table,
+			windows: true,
+			monitors: true,
+		}
+	}
+}
+
+impl ListSettings {
+	/**
+	 * Create a new ListSettings object.
+	 *
+	 * @param  format
+	 * @param  windows
+	 * @param  monitors
+	 * @return ListSettings
+	 */
+	pub fn new(format: ListFormat, windows: bool, monitors: bool) -> Self {
+		Self {
+			format,
+			windows,
+			monitors,
+		}
+	}
+
+	/**
+	 * Create a new ListSettings object from arguments.
+	 *
+	 * @param  matches
+	 * @return ListSettings
+	 */
+	pub fn from_args(matches: &ArgMatches<'_>) -> Self {
+		Self::from_parser(ArgParser::from_subcommand(matches, "list"))
+	}
+
+	/**
+	 * Create a ListSettings object from an argument parser.
+	 *
+	 * @param  parser
+	 * @return ListSettings
+	 */
+	fn from_parser(parser: ArgParser<'_>) -> Self {
+		match parser.args {
+			Some(matches) => {
+				let (windows, monitors) = (
+					matches.is_present("windows"),
+					matches.is_present("monitors"),
+				);
+				Self::new(
+					match matches.value_of("format") {
+						Some("json") => ListFormat::Json,
+						_ => ListFormat::Table,
+					},
+					windows || !monitors,
+					monitors || !windows,
+				)
+			}
+			None => Self::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use clap::{App, Arg};
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_list_settings() {
+		let args = App::new("test")
+			.arg(Arg::with_name("format").long("format").takes_value(true))
+			.arg(Arg::with_name("windows").long("windows"))
+			.arg(Arg::with_name("monitors").long("monitors"))
+			.get_matches_from(vec!["test", "--format", "json", "--monitors"]);
+		let list_settings = ListSettings::from_parser(ArgParser::from_args(&args));
+		assert_eq!(ListFormat::Json, list_settings.format);
+		assert!(!list_settings.windows);
+		assert!(list_settings.monitors);
+		let list_settings = ListSettings::default();
+		assert_eq!(ListFormat::Table, list_settings.format);
+		assert!(list_settings.windows);
+		assert!(list_settings.monitors);
+	}
+}
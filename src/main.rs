@@ -10,10 +10,14 @@ mod anim;
 mod apng;
 mod app;
 mod args;
+#[cfg(feature = "av1")]
+mod av1;
+mod avi;
 mod edit;
 mod file;
 mod gif;
 mod image;
+mod list;
 mod record;
 mod settings;
 mod util;
@@ -26,6 +30,7 @@ mod x11;
 use self::app::App;
 use self::args::matches::ArgMatches;
 use self::args::Args;
+use self::list::WindowList;
 use self::settings::AppSettings;
 use self::util::logger::Logger;
 use self::window::Access;
@@ -33,6 +38,7 @@ use self::window::Access;
 use self::ws::WindowSystem;
 #[cfg(all(unix, not(target_os = "macos")))]
 use self::x11::WindowSystem;
+use std::sync::Mutex;
 
 fn main() {
 	let args = Args::parse();
@@ -42,15 +48,61 @@ fn main() {
 		.init()
 		.expect("Failed to initialize the logger");
 	settings.check();
+	if settings.args.is_present("list") {
+		match WindowSystem::init(&settings) {
+			Some(ws) => {
+				print!(
+					"{}",
+					WindowList::new(
+						ws.get_windows(),
+						ws.get_monitors(),
+						&settings.list
+					)
+					.get_output()
+				);
+				return;
+			}
+			None => {
+				error!("Failed to access the window system.");
+				std::process::exit(1);
+			}
+		}
+	}
+	let mut process = None;
 	let window = if settings.window_required {
 		match WindowSystem::init(&settings) {
-			Some(mut ws) => match ws.get_window() {
-				Some(window) => Some(window),
-				None => {
-					error!("Failed to retrieve the window.");
+			Some(mut ws) if settings.record.flag.session => {
+				if let Err(e) = App::start_session(&mut ws, &settings) {
+					error!("{}", e);
 					std::process::exit(1);
 				}
-			},
+				return;
+			}
+			Some(mut ws) => {
+				if let (Some(_), Some(command)) = (
+					settings.record.wait_for_window,
+					settings.record.get_command(),
+				) {
+					match command.spawn() {
+						Ok(child) => process = Some(child),
+						Err(e) => {
+							error!("Failed to run the command. {}", e);
+							std::process::exit(1);
+						}
+					}
+				}
+				match ws.get_window() {
+					Ok(window) => Some(window),
+					Err(e) => {
+						if let Some(mut child) = process.take() {
+							let _ = child.kill();
+							let _ = child.wait();
+						}
+						error!("Failed to retrieve the window. {}", e);
+						std::process::exit(1);
+					}
+				}
+			}
 			None => {
 				error!("Failed to access the window system.");
 				std::process::exit(1);
@@ -59,7 +111,10 @@ fn main() {
 	} else {
 		None
 	};
-	if let Err(e) = App::new(window, &settings).start() {
+	let process = Mutex::new(process);
+	let mut app = App::new(window, &settings);
+	app.set_process(&process);
+	if let Err(e) = app.start() {
 		error!("{}", e);
 		std::process::exit(1);
 	}
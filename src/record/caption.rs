@@ -0,0 +1,226 @@
+use crate::image::font;
+use crate::image::Image;
+use crate::record::settings::RecordCorner;
+use crate::util::state::InputState;
+use device_query::Keycode;
+use image::Rgba;
+use std::time::{Duration, Instant};
+
+/* Duration of showing the caption before fading out */
+const CAPTION_DURATION: Duration = Duration::from_millis(1500);
+/* Duration of the fade out */
+const FADE_DURATION: Duration = Duration::from_millis(500);
+/* Maximum number of key combinations to show */
+const MAX_COMBINATIONS: usize = 5;
+/* Scale factor of the caption font */
+const FONT_SCALE: u32 = 3;
+/* Distance between the caption and the frame border */
+const CAPTION_MARGIN: u32 = 10;
+/* Modifier keys that are shown before the other keys */
+const MODIFIER_KEYS: [Keycode; 7] = [
+	Keycode::LControl,
+	Keycode::RControl,
+	Keycode::LAlt,
+	Keycode::RAlt,
+	Keycode::LShift,
+	Keycode::RShift,
+	Keycode::Meta,
+];
+
+/* Caption of the pressed key combinations */
+#[derive(Debug)]
+pub struct KeyCaption {
+	corner: RecordCorner,
+	keys: Vec<Keycode>,
+	combinations: Vec<String>,
+	updated: Instant,
+}
+
+impl KeyCaption {
+	/**
+	 * Create a new KeyCaption object.
+	 *
+	 * @param  corner
+	 * @return KeyCaption
+	 */
+	pub fn new(corner: RecordCorner) -> Self {
+		Self {
+			corner,
+			keys: Vec::new(),
+			combinations: Vec::new(),
+			updated: Instant::now(),
+		}
+	}
+
+	/**
+	 * Update the caption with the currently pressed keys.
+	 *
+	 * @param  keys
+	 * @param  input_state (Option)
+	 * @param  now
+	 */
+	pub fn update(
+		&mut self,
+		keys: Vec<Keycode>,
+		input_state: Option<&InputState>,
+		now: Instant,
+	) {
+		if keys == self.keys {
+			return;
+		}
+		self.keys = keys.clone();
+		if keys.iter().all(|key| MODIFIER_KEYS.contains(key))
+			|| input_state.is_some_and(|state| {
+				state.action_keys.check(keys.clone())
+					|| state.cancel_keys.check(keys.clone())
+			}) {
+			return;
+		}
+		if self.get_opacity(now) == 0. {
+			self.combinations.clear();
+		}
+		self.combinations.push(Self::format_keys(&keys));
+		if self.combinations.len() > MAX_COMBINATIONS {
+			self.combinations.remove(0);
+		}
+		self.updated = now;
+	}
+
+	/**
+	 * Format the key combination with the modifiers first.
+	 *
+	 * @param  keys
+	 * @return String
+	 */
+	fn format_keys(keys: &[Keycode]) -> String {
+		let (mut modifiers, others): (Vec<Keycode>, Vec<Keycode>) =
+			keys.iter().partition(|key| MODIFIER_KEYS.contains(key));
+		modifiers.extend(others);
+		modifiers
+			.iter()
+			.map(|key| {
+				let name = format!("{key:?}");
+				match name.strip_prefix("Key") {
+					Some(digit) => digit.to_string(),
+					None => name,
+				}
+			})
+			.collect::<Vec<String>>()
+			.join("-")
+	}
+
+	/**
+	 * Get the opacity of the caption at the given time.
+	 *
+	 * @param  now
+	 * @return f32
+	 */
+	fn get_opacity(&self, now: Instant) -> f32 {
+		let elapsed = now.saturating_duration_since(self.updated);
+		if self.combinations.is_empty() {
+			0.
+		} else if elapsed <= CAPTION_DURATION {
+			1.
+		} else {
+			(1. - (elapsed - CAPTION_DURATION).as_secs_f32()
+				/ FADE_DURATION.as_secs_f32())
+			.max(0.)
+		}
+	}
+
+	/**
+	 * Draw the caption on the corner of the given frame.
+	 *
+	 * @param  image
+	 * @param  now
+	 */
+	pub fn render(&self, image: &mut Image, now: Instant) {
+		let opacity = self.get_opacity(now);
+		if opacity == 0. {
+			return;
+		}
+		let caption = font::render_text(
+			&self.combinations.join(" "),
+			FONT_SCALE,
+			Rgba::from([255, 255, 255, (255. * opacity) as u8]),
+			Rgba::from([0, 0, 0, (160. * opacity) as u8]),
+		);
+		let (left, top) = (
+			i32::try_from(CAPTION_MARGIN).unwrap_or_default(),
+			i32::try_from(CAPTION_MARGIN).unwrap_or_default(),
+		);
+		let (right, bottom) = (
+			i32::try_from(image.geometry.width).unwrap_or_default()
+				- i32::try_from(caption.geometry.width + CAPTION_MARGIN)
+					.unwrap_or_default(),
+			i32::try_from(image.geometry.height).unwrap_or_default()
+				- i32::try_from(caption.geometry.height + CAPTION_MARGIN)
+					.unwrap_or_default(),
+		);
+		let (x, y) = match self.corner {
+			RecordCorner::TopLeft => (left, top),
+			RecordCorner::TopRight => (right, top),
+			RecordCorner::BottomLeft => (left, bottom),
+			RecordCorner::BottomRight => (right, bottom),
+		};
+		image.blend(&caption, x, y);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::ExtendedColorType;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_key_caption() {
+		let now = Instant::now();
+		let mut caption = KeyCaption::new(RecordCorner::TopLeft);
+		assert_eq!(0., caption.get_opacity(now));
+		caption.update(vec![Keycode::LControl], None, now);
+		assert!(caption.combinations.is_empty());
+		caption.update(vec![Keycode::C, Keycode::LControl], None, now);
+		caption.update(vec![Keycode::C, Keycode::LControl], None, now);
+		caption.update(vec![Keycode::Key1], None, now);
+		assert_eq!(vec!["LControl-C", "1"], caption.combinations);
+		assert_eq!(1., caption.get_opacity(now));
+		assert_eq!(1., caption.get_opacity(now + CAPTION_DURATION));
+		assert_eq!(
+			0.5,
+			caption.get_opacity(now + CAPTION_DURATION + FADE_DURATION / 2)
+		);
+		assert_eq!(
+			0.,
+			caption.get_opacity(now + CAPTION_DURATION + FADE_DURATION)
+		);
+		for i in 0..MAX_COMBINATIONS {
+			caption.update(vec![Keycode::A], None, now);
+			caption.update(
+				if i % 2 == 0 {
+					vec![Keycode::B]
+				} else {
+					Vec::new()
+				},
+				None,
+				now,
+			);
+		}
+		assert_eq!(MAX_COMBINATIONS, caption.combinations.len());
+		let mut image = Image::new(
+			vec![Rgba::from([255, 255, 255, 255]); 100 * 50],
+			false,
+			Geometry::new(0, 0, 100, 50),
+		);
+		caption.render(&mut image, now);
+		let data = image.get_data(ExtendedColorType::L8);
+		assert_eq!(255, data[0]);
+		assert!(data[100 * 10 + 10] < 255);
+		caption.update(
+			vec![Keycode::C],
+			None,
+			now + CAPTION_DURATION + FADE_DURATION,
+		);
+		assert_eq!(vec!["C"], caption.combinations);
+	}
+}
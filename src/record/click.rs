@@ -0,0 +1,146 @@
+use crate::image::geometry::Geometry;
+use crate::image::Image;
+use device_query::MouseState;
+use image::Rgba;
+use std::time::{Duration, Instant};
+
+/* Duration of the ripple animation */
+const RIPPLE_DURATION: Duration = Duration::from_millis(400);
+/* Radius of the ripple when the button is pressed */
+const MIN_RADIUS: f32 = 6.;
+/* Radius of the ripple at the end of the animation */
+const MAX_RADIUS: f32 = 24.;
+/* Width of the ripple ring */
+const RING_WIDTH: f32 = 3.;
+
+/* Animated highlights of the mouse clicks */
+#[derive(Debug)]
+pub struct ClickRipple {
+	color: u64,
+	pressed: bool,
+	clicks: Vec<((i32, i32), Instant)>,
+}
+
+impl ClickRipple {
+	/**
+	 * Create a new ClickRipple object.
+	 *
+	 * @param  color
+	 * @return ClickRipple
+	 */
+	pub fn new(color: u64) -> Self {
+		Self {
+			color,
+			pressed: false,
+			clicks: Vec::new(),
+		}
+	}
+
+	/**
+	 * Save the pointer position if a mouse button is pressed.
+	 *
+	 * @param  mouse
+	 */
+	pub fn update(&mut self, mouse: &MouseState) {
+		let pressed = mouse.button_pressed.iter().any(|pressed| *pressed);
+		if pressed && !self.pressed {
+			self.clicks.push((mouse.coords, Instant::now()));
+		}
+		self.pressed = pressed;
+	}
+
+	/**
+	 * Draw the ripples on the frame and remove the finished ones.
+	 *
+	 * @param  image
+	 * @param  area
+	 */
+	pub fn render(&mut self, image: &mut Image, area: Geometry) {
+		self.clicks
+			.retain(|(_, time)| time.elapsed() < RIPPLE_DURATION);
+		for ((x, y), time) in &self.clicks {
+			let progress =
+				time.elapsed().as_secs_f32() / RIPPLE_DURATION.as_secs_f32();
+			let ripple = self.get_ripple(progress.min(1.));
+			let offset =
+				i32::try_from(ripple.geometry.width / 2).unwrap_or_default();
+			image.blend(&ripple, x - area.x - offset, y - area.y - offset);
+		}
+	}
+
+	/**
+	 * Get the image of a ripple at the given animation progress.
+	 *
+	 * @param  progress
+	 * @return Image
+	 */
+	fn get_ripple(&self, progress: f32) -> Image {
+		let radius = MIN_RADIUS + (MAX_RADIUS - MIN_RADIUS) * progress;
+		let size = (MAX_RADIUS + RING_WIDTH) as u32 * 2 + 1;
+		let center = (size / 2) as f32;
+		let opacity = 1. - progress;
+		let (red, green, blue) = (
+			((self.color >> 16) & 0xFF) as u8,
+			((self.color >> 8) & 0xFF) as u8,
+			(self.color & 0xFF) as u8,
+		);
+		let mut data = Vec::new();
+		for y in 0..size {
+			for x in 0..size {
+				let distance = ((x as f32 - center).powi(2)
+					+ (y as f32 - center).powi(2))
+				.sqrt();
+				let alpha = if (distance - radius).abs() <= RING_WIDTH / 2. {
+					opacity
+				} else if distance < radius {
+					opacity * 0.3
+				} else {
+					0.
+				};
+				data.push(Rgba::from([red, green, blue, (alpha * 255.) as u8]));
+			}
+		}
+		Image::new(data, true, Geometry::new(0, 0, size, size))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::ExtendedColorType;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_click_ripple() {
+		let mut ripple = ClickRipple::new(0x00FF_0000);
+		let mut mouse = MouseState {
+			coords: (60, 40),
+			button_pressed: vec![false, true, false, false, false],
+		};
+		ripple.update(&mouse);
+		ripple.update(&mouse);
+		assert_eq!(1, ripple.clicks.len());
+		mouse.button_pressed[1] = false;
+		ripple.update(&mouse);
+		let mut image = Image::new(
+			vec![Rgba::from([0, 0, 0, 255]); 100 * 100],
+			false,
+			Geometry::new(0, 0, 100, 100),
+		);
+		ripple.render(&mut image, Geometry::new(10, 10, 100, 100));
+		let data = image.get_data(ExtendedColorType::Rgb8);
+		assert!(data[(30 * 100 + 50) * 3] > 0);
+		assert_eq!(0, data[(30 * 100 + 50) * 3 + 1]);
+		assert_eq!(0, data[0]);
+		let ring = ripple.get_ripple(0.);
+		let center = (ring.geometry.width / 2) as usize;
+		let alpha = ring.get_data(ExtendedColorType::Rgba8);
+		assert_eq!(
+			255,
+			alpha[(center * ring.geometry.width as usize + center + 6) * 4 + 3]
+		);
+		assert_eq!(0, alpha[3]);
+		ripple.clicks[0].1 -= RIPPLE_DURATION;
+		ripple.render(&mut image, Geometry::default());
+		assert!(ripple.clicks.is_empty());
+	}
+}
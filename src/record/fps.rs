@@ -5,7 +5,8 @@ use std::time::{Duration, Instant};
 #[derive(Clone, Copy, Debug)]
 pub struct FpsClock {
 	pub fps: u32,
-	last_tick_time: Instant,
+	start_time: Instant,
+	tick_count: u32,
 }
 
 impl FpsClock {
@@ -18,24 +19,45 @@ impl FpsClock {
 	pub fn new(fps: u32) -> Self {
 		Self {
 			fps,
-			last_tick_time: Instant::now(),
+			start_time: Instant::now(),
+			tick_count: 0,
 		}
 	}
 
 	/**
-	 * Sleep the thread to run at the correct FPS.
+	 * Get the delay between the frames.
 	 *
-	 * @return f32
+	 * @return Duration
 	 */
-	pub fn tick(&mut self) -> f32 {
-		let t = self.last_tick_time.elapsed();
-		let total_nanos = t.as_secs() * 1e9 as u64 + t.subsec_nanos() as u64;
-		let diff = ((1. / self.fps as f32) * 1e9) - (total_nanos as f32);
-		if diff > 0. {
-			thread::sleep(Duration::new(0, diff as u32))
-		}
-		self.last_tick_time = Instant::now();
-		diff
+	pub fn get_frame_delay(&self) -> Duration {
+		Duration::from_secs_f64(1. / f64::from(self.fps.max(1)))
+	}
+
+	/* Start the timeline of the ticks from now. */
+	pub fn reset(&mut self) {
+		self.start_time = Instant::now();
+		self.tick_count = 0;
+	}
+
+	/**
+	 * Sleep the thread until the next tick on the timeline,
+	 * skip the ticks that are already missed.
+	 *
+	 * @return u32
+	 */
+	pub fn tick(&mut self) -> u32 {
+		let delay = self.get_frame_delay();
+		let elapsed = self.start_time.elapsed();
+		let target = delay * self.tick_count;
+		let skipped = if elapsed < target {
+			thread::sleep(target - elapsed);
+			0
+		} else {
+			u32::try_from((elapsed - target).as_nanos() / delay.as_nanos())
+				.unwrap_or(u32::MAX)
+		};
+		self.tick_count = self.tick_count.saturating_add(skipped.saturating_add(1));
+		skipped
 	}
 }
 
@@ -47,11 +69,17 @@ mod tests {
 	#[test]
 	fn test_fps() {
 		let mut fps_clock = FpsClock::new(100);
-		let fps = (1. / fps_clock.fps as f32) * 1e9;
-		assert_eq!(1e7, fps);
-		for i in 0..2 {
-			thread::sleep(Duration::from_nanos(i));
-			assert!(fps > fps_clock.tick());
-		}
+		assert_eq!(Duration::from_millis(10), fps_clock.get_frame_delay());
+		fps_clock.reset();
+		assert_eq!(0, fps_clock.tick());
+		fps_clock.tick();
+		assert!(fps_clock.start_time.elapsed() >= Duration::from_millis(10));
+		thread::sleep(Duration::from_millis(35));
+		assert!(fps_clock.tick() >= 2);
+		fps_clock.tick();
+		assert!(
+			fps_clock.start_time.elapsed()
+				>= fps_clock.get_frame_delay() * (fps_clock.tick_count - 1)
+		);
 	}
 }
@@ -1,16 +1,34 @@
+pub mod caption;
+pub mod click;
 pub mod fps;
+pub mod pause;
+pub mod replay;
+pub mod sequence;
 pub mod settings;
+pub mod stats;
+pub mod viewport;
 
+use crate::anim::store::FrameStore;
+use crate::anim::TimedFrame;
 use crate::app::{AppError, AppResult};
+use crate::image::geometry::Geometry;
 use crate::image::Image;
+use crate::record::caption::KeyCaption;
+use crate::record::click::ClickRipple;
 use crate::record::fps::FpsClock;
+use crate::record::pause::RecordPause;
+use crate::record::replay::ReplayBuffer;
 use crate::record::settings::RecordSettings;
+use crate::record::stats::RecordStats;
+use crate::record::viewport::Viewport;
 use crate::util::state::InputState;
 use crate::window::Capture;
+use device_query::{DeviceQuery, DeviceState};
 use std::io::{self, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc, Arc};
 use std::thread;
+use std::time::{Duration, Instant};
 
 /* Asynchronous recording result */
 #[derive(Debug)]
@@ -32,16 +50,13 @@ impl<T> RecordResult<T> {
 	}
 
 	/**
-	 * Stop the thread and retrieve values.
+	 * Stop the thread (if it is still running) and retrieve values.
 	 *
-	 * @return Option
+	 * @return Result
 	 */
-	pub fn get(self) -> Option<thread::Result<T>> {
-		if self.sender.send(()).is_ok() {
-			Some(self.thread.join())
-		} else {
-			None
-		}
+	pub fn get(self) -> thread::Result<T> {
+		let _ = self.sender.send(());
+		self.thread.join()
 	}
 }
 
@@ -51,7 +66,16 @@ pub struct Recorder<Window> {
 	clock: FpsClock,
 	channel: (mpsc::Sender<()>, mpsc::Receiver<()>),
 	gifski: bool,
+	memory_limit: u64,
+	collapse: Option<u32>,
+	last_frame: Option<(Image, Duration, Duration)>,
+	replay: Option<ReplayBuffer>,
+	sender: Option<mpsc::SyncSender<TimedFrame>>,
 	settings: RecordSettings,
+	geometry: Option<Geometry>,
+	viewport: Option<Viewport>,
+	caption: Option<KeyCaption>,
+	ripple: Option<ClickRipple>,
 }
 
 impl<Window> Recorder<Window>
@@ -64,6 +88,8 @@ where
 	 * @param  window
 	 * @param  fps
 	 * @param  gifski
+	 * @param  memory_limit
+	 * @param  collapse (Option)
 	 * @param  settings
 	 * @return Recorder
 	 */
@@ -71,6 +97,8 @@ where
 		window: Window,
 		fps: u32,
 		gifski: bool,
+		memory_limit: u64,
+		collapse: Option<u32>,
 		settings: RecordSettings,
 	) -> Self {
 		Self {
@@ -78,7 +106,42 @@ where
 			clock: FpsClock::new(fps),
 			channel: mpsc::channel(),
 			gifski,
+			memory_limit,
+			collapse,
+			last_frame: None,
+			replay: settings.time.replay.map(|duration| {
+				ReplayBuffer::new(Duration::from_secs_f64(duration), memory_limit)
+			}),
+			sender: None,
 			settings,
+			geometry: None,
+			viewport: settings.viewport.map(Viewport::new),
+			caption: settings.flag.keys.map(KeyCaption::new),
+			ripple: settings
+				.flag
+				.clicks
+				.then(|| ClickRipple::new(settings.color)),
+		}
+	}
+
+	/**
+	 * Send the recorded frames to the given channel instead of storing them.
+	 *
+	 * @param  sender
+	 */
+	pub fn set_sender(&mut self, sender: mpsc::SyncSender<TimedFrame>) {
+		self.sender = Some(sender);
+	}
+
+	/**
+	 * Get the frame store for saving the recorded frames.
+	 *
+	 * @return FrameStore
+	 */
+	fn get_frame_store(&mut self) -> FrameStore {
+		match self.sender.take() {
+			Some(sender) => FrameStore::with_sender(sender),
+			None => FrameStore::new(self.memory_limit),
 		}
 	}
 
@@ -88,7 +151,13 @@ where
 	 * @return usize
 	 */
 	fn get_max_frames(&self) -> usize {
-		if let Some(duration) = self.settings.time.duration {
+		if let Some(duration) = self.settings.time.replay {
+			info!(
+				"Recording {} FPS, keeping the last {} seconds...",
+				self.clock.fps, duration
+			);
+			usize::MAX
+		} else if let Some(duration) = self.settings.time.duration {
 			info!(
 				"Recording {} FPS for {} seconds...",
 				self.clock.fps, duration
@@ -105,72 +174,267 @@ where
 		}
 	}
 
+	/**
+	 * Get an image from the window, scaled to the initial size if it is resized.
+	 *
+	 * @param  session
+	 * @return Image (Option)
+	 */
+	fn get_image(&mut self, session: &mut Window::Session) -> Option<Image> {
+		if !self.settings.flag.follow {
+			return self.window.get_session_image(session);
+		}
+		self.window.update_geometry();
+		let image = self.window.get_session_image(session)?;
+		match self.geometry {
+			Some(geometry)
+				if (geometry.width, geometry.height)
+					!= (image.geometry.width, image.geometry.height) =>
+			{
+				Some(image.resize(geometry.width, geometry.height))
+			}
+			Some(_) => Some(image),
+			None => {
+				self.geometry = Some(image.geometry);
+				Some(image)
+			}
+		}
+	}
+
+	/**
+	 * Hold back the frame until the next frame is captured for
+	 * calculating its delay, then add the previous frame to the store.
+	 * Similar frames are merged with respect to the collapse threshold.
+	 *
+	 * @param  frames
+	 * @param  image
+	 * @param  timestamp
+	 * @return Result
+	 */
+	fn push_frame(
+		&mut self,
+		frames: &mut FrameStore,
+		image: Image,
+		timestamp: Duration,
+	) -> AppResult<()> {
+		if let (Some(threshold), Some((last_image, _, last_timestamp))) =
+			(self.collapse, self.last_frame.as_mut())
+		{
+			if last_image.is_similar(&image, threshold) {
+				*last_timestamp = timestamp;
+				return Ok(());
+			}
+		}
+		match self.last_frame.replace((image, timestamp, timestamp)) {
+			Some((image, start, _)) => {
+				self.store_frame(frames, image, timestamp.saturating_sub(start))
+			}
+			None => Ok(()),
+		}
+	}
+
+	/**
+	 * Add the frame to the replay buffer if it exists or to the store.
+	 *
+	 * @param  frames
+	 * @param  image
+	 * @param  delay
+	 * @return Result
+	 */
+	fn store_frame(
+		&mut self,
+		frames: &mut FrameStore,
+		image: Image,
+		delay: Duration,
+	) -> AppResult<()> {
+		match self.replay.as_mut() {
+			Some(replay) => {
+				replay.push(image, delay);
+				Ok(())
+			}
+			None => frames.push(image, delay),
+		}
+	}
+
+	/**
+	 * Add the frame that is held back and the frames in the
+	 * replay buffer to the store.
+	 *
+	 * @param  frames
+	 * @param  frame_count
+	 * @return Result
+	 */
+	fn flush_frame(
+		&mut self,
+		frames: &mut FrameStore,
+		frame_count: usize,
+	) -> AppResult<()> {
+		if let Some((image, start, end)) = self.last_frame.take() {
+			let delay = end.saturating_sub(start) + self.clock.get_frame_delay();
+			self.store_frame(frames, image, delay)?;
+		}
+		if let Some(replay) = self.replay.as_mut() {
+			replay.drain(frames)?;
+		}
+		if self.collapse.is_some() {
+			debug!("Collapsed {} frames into {}", frame_count, frames.len());
+		}
+		Ok(())
+	}
+
 	/**
 	 * Record frames synchronously with blocking the current thread.
 	 *
 	 * @param  input_state (Option)
-	 * @return Vector of Image
+	 * @return FrameStore (Result)
 	 */
 	pub fn record_sync(
 		&mut self,
 		input_state: Option<&InputState>,
-	) -> AppResult<Vec<Image>> {
-		let mut frames = Vec::new();
+	) -> AppResult<FrameStore> {
+		let mut frames = self.get_frame_store();
 		let recording = Arc::new(AtomicBool::new(true));
 		let rec_state = recording.clone();
 		ctrlc::set_handler(move || {
 			rec_state.store(false, Ordering::SeqCst);
 		})?;
 		self.window.show_countdown();
+		let mut session = self.window.init_capture();
 		let max_frames = self.get_max_frames();
-		while recording.load(Ordering::SeqCst) && frames.len() < max_frames {
+		let bounds = self.window.get_area();
+		let device_state = (self.viewport.is_some()
+			|| self.caption.is_some()
+			|| self.ripple.is_some())
+		.then(|| input_state.map_or_else(DeviceState::new, |v| v.state.clone()));
+		let mut pause = RecordPause::init();
+		let mut stats = RecordStats::default();
+		let start_time = Instant::now();
+		self.clock.reset();
+		while recording.load(Ordering::SeqCst)
+			&& stats.frames + stats.dropped < max_frames
+		{
 			if let Some(state) = input_state {
 				if state.check_cancel_keys() {
 					frames.clear();
-					debug!("\n");
+					self.last_frame = None;
+					if let Some(replay) = self.replay.as_mut() {
+						replay.clear();
+					}
+					info!("\n");
 					warn!("User interrupt detected.");
 					break;
 				} else if state.check_action() {
 					break;
 				}
 			}
-			self.clock.tick();
-			frames.push(self.window.get_image().ok_or_else(|| {
+			let skipped = self.clock.tick();
+			if pause.update(input_state.is_some_and(|v| v.check_pause_keys())) {
+				info!("Frames: {} (paused)\r", stats.frames);
+				io::stdout().flush()?;
+				continue;
+			}
+			stats.add_dropped(skipped);
+			let capture_time = Instant::now();
+			let timestamp = capture_time
+				.duration_since(start_time)
+				.saturating_sub(pause.get_paused_time());
+			if let (Some(viewport), Some(state)) =
+				(self.viewport.as_mut(), &device_state)
+			{
+				self.window
+					.set_area(viewport.update(state.get_mouse().coords, bounds));
+			}
+			let mut image = self.get_image(&mut session).ok_or_else(|| {
 				AppError::FrameError(String::from("Failed to get image"))
-			})?);
-			debug!("Frames: {}\r", frames.len());
+			})?;
+			if let (Some(caption), Some(state)) =
+				(self.caption.as_mut(), &device_state)
+			{
+				caption.update(state.get_keys(), input_state, capture_time);
+				caption.render(&mut image, capture_time);
+			}
+			if let (Some(ripple), Some(state)) =
+				(self.ripple.as_mut(), &device_state)
+			{
+				ripple.update(&state.get_mouse());
+				ripple.render(&mut image, self.window.get_root_area());
+			}
+			stats.add_frame(capture_time.elapsed());
+			self.push_frame(&mut frames, image, timestamp)?;
+			info!("Frames: {} {:<8}\r", stats.frames, " ");
 			io::stdout().flush()?;
 		}
-		debug!("\n");
+		info!("\n");
+		drop(session);
+		self.flush_frame(&mut frames, stats.frames)?;
+		stats.log(
+			self.clock.fps,
+			start_time.elapsed().saturating_sub(pause.get_paused_time()),
+		);
 		Ok(frames)
 	}
 
 	/**
 	 * Record frames asynchronously and without blocking.
 	 *
+	 * Recording stops early if a frame cannot be captured or stored.
+	 *
+	 * @param  input_state (Option)
 	 * @return RecordResult
 	 */
-	pub fn record_async(mut self) -> RecordResult<Vec<Image>> {
-		let mut frames = Vec::new();
+	pub fn record_async(
+		mut self,
+		input_state: Option<&'static InputState>,
+	) -> RecordResult<AppResult<FrameStore>> {
 		RecordResult::new(
 			self.channel.0.clone(),
 			thread::spawn(move || {
+				let mut frames = self.get_frame_store();
 				self.window.show_countdown();
+				let mut session = self.window.init_capture();
 				let max_frames = self.get_max_frames();
-				while self.channel.1.try_recv().is_err() {
-					self.clock.tick();
-					if frames.len() < max_frames {
-						frames.push(
-							self.window
-								.get_image()
-								.expect("Failed to get the image"),
-						);
-						debug!("Frames: {}\r", frames.len());
+				let mut pause = RecordPause::init();
+				let mut stats = RecordStats::default();
+				let start_time = Instant::now();
+				self.clock.reset();
+				let mut result = Ok(());
+				while result.is_ok() && self.channel.1.try_recv().is_err() {
+					let skipped = self.clock.tick();
+					if pause
+						.update(input_state.is_some_and(|v| v.check_pause_keys()))
+					{
+						info!("Frames: {} (paused)\r", stats.frames);
+						io::stdout().flush().expect("Failed to flush stdout");
+					} else if stats.frames + stats.dropped < max_frames {
+						stats.add_dropped(skipped);
+						let capture_time = Instant::now();
+						let timestamp = capture_time
+							.duration_since(start_time)
+							.saturating_sub(pause.get_paused_time());
+						result = self
+							.get_image(&mut session)
+							.ok_or_else(|| {
+								AppError::FrameError(String::from(
+									"Failed to get image",
+								))
+							})
+							.and_then(|image| {
+								stats.add_frame(capture_time.elapsed());
+								self.push_frame(&mut frames, image, timestamp)
+							});
+						info!("Frames: {} {:<8}\r", stats.frames, " ");
 						io::stdout().flush().expect("Failed to flush stdout");
 					}
 				}
-				debug!("\n");
-				frames
+				info!("\n");
+				drop(session);
+				result?;
+				self.flush_frame(&mut frames, stats.frames)?;
+				stats.log(
+					self.clock.fps,
+					start_time.elapsed().saturating_sub(pause.get_paused_time()),
+				);
+				Ok(frames)
 			}),
 		)
 	}
@@ -179,21 +443,86 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::anim::store::STREAM_BUFFER;
 	use crate::record::settings::RecordSettings;
 	use crate::window::test::TestWindow;
-	use pretty_assertions::assert_ne;
+	use pretty_assertions::{assert_eq, assert_ne};
 	use std::thread;
 	use std::time::Duration;
 	#[test]
 	fn test_record() {
 		let window = TestWindow::default();
-		let recorder = Recorder::new(window, 10, false, RecordSettings::default());
-		let record = recorder.record_async();
-		thread::sleep(Duration::from_millis(200));
-		assert!(!record.get().unwrap().unwrap().is_empty());
-		let mut recorder =
-			Recorder::new(window, 10, false, RecordSettings::default());
+		let recorder =
+			Recorder::new(window, 10, false, 0, None, RecordSettings::default());
+		let record = recorder.record_async(None);
+		thread::sleep(Duration::from_millis(250));
+		let frames = record.get().unwrap().unwrap();
+		assert!(frames.len() > 1);
+		assert!(frames.get_delay(0) >= Duration::from_millis(90));
+		let mut recorder = Recorder::new(
+			window,
+			10,
+			false,
+			u64::MAX,
+			None,
+			RecordSettings::default(),
+		);
 		recorder.settings.time.duration = Some(0.2);
 		assert_ne!(0, recorder.record_sync(None).unwrap().len());
+		let mut settings = RecordSettings::default();
+		settings.time.replay = Some(0.2);
+		let recorder = Recorder::new(window, 10, false, u64::MAX, None, settings);
+		let record = recorder.record_async(None);
+		thread::sleep(Duration::from_millis(600));
+		let frames = record.get().unwrap().unwrap();
+		assert!(frames.len() < 5);
+		assert!(frames.len() >= 2);
+		let recorder = Recorder::new(
+			window,
+			10,
+			false,
+			u64::MAX,
+			Some(0),
+			RecordSettings::default(),
+		);
+		let record = recorder.record_async(None);
+		thread::sleep(Duration::from_millis(300));
+		let frames = record.get().unwrap().unwrap();
+		assert_eq!(1, frames.len());
+		assert!(frames.get_delay(0) > Duration::from_millis(100));
+		let mut recorder =
+			Recorder::new(window, 10, false, 0, None, RecordSettings::default());
+		let (sender, receiver) = mpsc::sync_channel(STREAM_BUFFER);
+		recorder.set_sender(sender);
+		let record = recorder.record_async(None);
+		thread::sleep(Duration::from_millis(200));
+		let frame_count = record.get().unwrap().unwrap().len();
+		assert_eq!(frame_count, receiver.iter().count());
+		let mut recorder =
+			Recorder::new(window, 10, false, 0, None, RecordSettings::default());
+		let (sender, receiver) = mpsc::sync_channel(STREAM_BUFFER);
+		recorder.set_sender(sender);
+		drop(receiver);
+		let record = recorder.record_async(None);
+		thread::sleep(Duration::from_millis(300));
+		assert!(record.get().unwrap().is_err());
+		let mut recorder = Recorder::new(
+			window,
+			10,
+			false,
+			u64::MAX,
+			None,
+			RecordSettings::default(),
+		);
+		recorder.settings.flag.follow = true;
+		assert_eq!(
+			Some(window.geometry),
+			recorder.get_image(&mut ()).map(|image| image.geometry)
+		);
+		recorder.geometry = Some(Geometry::new(0, 0, 2, 2));
+		assert_eq!(
+			Some(Geometry::new(0, 0, 2, 2)),
+			recorder.get_image(&mut ()).map(|image| image.geometry)
+		);
 	}
 }
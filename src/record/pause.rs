@@ -0,0 +1,118 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+/* No pending pause request */
+const REQUEST_NONE: u8 = 0;
+/* Pause request (SIGUSR1) */
+const REQUEST_PAUSE: u8 = 1;
+/* Resume request (SIGUSR2) */
+const REQUEST_RESUME: u8 = 2;
+/* Pending request that is set by the signal handler */
+static REQUEST: AtomicU8 = AtomicU8::new(REQUEST_NONE);
+
+/* Paused state of the recording */
+#[derive(Debug, Default)]
+pub struct RecordPause {
+	paused: bool,
+	keys_pressed: bool,
+	paused_since: Option<Instant>,
+	paused_time: Duration,
+}
+
+impl RecordPause {
+	/**
+	 * Create a new RecordPause object and handle the pause/resume signals.
+	 *
+	 * @return RecordPause
+	 */
+	pub fn init() -> Self {
+		REQUEST.store(REQUEST_NONE, Ordering::SeqCst);
+		#[cfg(all(unix, not(target_os = "macos")))]
+		unsafe {
+			libc::signal(
+				libc::SIGUSR1,
+				handle_signal as extern "C" fn(libc::c_int) as libc::sighandler_t,
+			);
+			libc::signal(
+				libc::SIGUSR2,
+				handle_signal as extern "C" fn(libc::c_int) as libc::sighandler_t,
+			);
+		}
+		Self::default()
+	}
+
+	/**
+	 * Update the paused state with respect to the pause keys and signals.
+	 *
+	 * @param  keys_pressed
+	 * @return bool
+	 */
+	pub fn update(&mut self, keys_pressed: bool) -> bool {
+		if keys_pressed && !self.keys_pressed {
+			self.paused = !self.paused;
+		}
+		self.keys_pressed = keys_pressed;
+		match REQUEST.swap(REQUEST_NONE, Ordering::SeqCst) {
+			REQUEST_PAUSE => self.paused = true,
+			REQUEST_RESUME => self.paused = false,
+			_ => {}
+		}
+		match (self.paused, self.paused_since) {
+			(true, None) => self.paused_since = Some(Instant::now()),
+			(false, Some(since)) => {
+				self.paused_time += since.elapsed();
+				self.paused_since = None;
+			}
+			_ => {}
+		}
+		self.paused
+	}
+
+	/**
+	 * Get the total duration of the pauses.
+	 *
+	 * @return Duration
+	 */
+	pub fn get_paused_time(&self) -> Duration {
+		self.paused_time
+			+ self
+				.paused_since
+				.map(|since| since.elapsed())
+				.unwrap_or_default()
+	}
+}
+
+/**
+ * Save the pause/resume request for the next update.
+ *
+ * @param signal
+ */
+#[cfg(all(unix, not(target_os = "macos")))]
+extern "C" fn handle_signal(signal: libc::c_int) {
+	REQUEST.store(
+		if signal == libc::SIGUSR1 {
+			REQUEST_PAUSE
+		} else {
+			REQUEST_RESUME
+		},
+		Ordering::SeqCst,
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::thread;
+	#[test]
+	fn test_record_pause() {
+		let mut pause = RecordPause::default();
+		assert!(!pause.update(false));
+		assert!(pause.update(true));
+		assert!(pause.update(true));
+		assert!(pause.update(false));
+		thread::sleep(Duration::from_millis(10));
+		assert!(!pause.update(true));
+		assert!(pause.get_paused_time() >= Duration::from_millis(10));
+		assert!(!pause.update(false));
+	}
+}
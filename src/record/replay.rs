@@ -0,0 +1,157 @@
+use crate::anim::store::FrameStore;
+use crate::anim::TimedFrame;
+use crate::app::AppResult;
+use crate::image::Image;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/* Ring buffer that keeps the last recorded frames */
+#[derive(Debug)]
+pub struct ReplayBuffer {
+	duration: Duration,
+	memory_limit: u64,
+	frames: VecDeque<TimedFrame>,
+	total_delay: Duration,
+	memory_usage: u64,
+	limited: bool,
+}
+
+impl ReplayBuffer {
+	/**
+	 * Create a new ReplayBuffer object.
+	 *
+	 * @param  duration
+	 * @param  memory_limit
+	 * @return ReplayBuffer
+	 */
+	pub fn new(duration: Duration, memory_limit: u64) -> Self {
+		Self {
+			duration,
+			memory_limit,
+			frames: VecDeque::new(),
+			total_delay: Duration::ZERO,
+			memory_usage: 0,
+			limited: false,
+		}
+	}
+
+	/**
+	 * Get the memory size of the given frame.
+	 *
+	 * @param  image
+	 * @return u64
+	 */
+	fn get_size(image: &Image) -> u64 {
+		u64::from(image.geometry.width) * u64::from(image.geometry.height) * 4
+	}
+
+	/**
+	 * Add a frame to the buffer and drop the oldest frames
+	 * that are outside of the replay duration or memory limit.
+	 *
+	 * Warns once if the memory limit shortens the replay duration.
+	 *
+	 * @param  image
+	 * @param  delay
+	 */
+	pub fn push(&mut self, image: Image, delay: Duration) {
+		self.total_delay += delay;
+		self.memory_usage += Self::get_size(&image);
+		self.frames.push_back((image, delay));
+		while let Some((image, delay)) = self.frames.front() {
+			let (size, delay) = (Self::get_size(image), *delay);
+			if self.frames.len() == 1
+				|| (self.total_delay - delay < self.duration
+					&& self.memory_usage <= self.memory_limit)
+			{
+				break;
+			}
+			self.frames.pop_front();
+			self.total_delay -= delay;
+			self.memory_usage -= size;
+			if self.total_delay < self.duration && !self.limited {
+				self.limited = true;
+				warn!(
+					"Memory limit is reached, keeping the last {:.1} seconds \
+					instead of {:.1}.",
+					self.total_delay.as_secs_f64(),
+					self.duration.as_secs_f64()
+				);
+			}
+		}
+	}
+
+	/**
+	 * Get the number of frames.
+	 *
+	 * @return usize
+	 */
+	#[allow(dead_code)]
+	pub fn len(&self) -> usize {
+		self.frames.len()
+	}
+
+	/**
+	 * Move the frames in the buffer to the given store.
+	 *
+	 * @param  store
+	 * @return Result
+	 */
+	pub fn drain(&mut self, store: &mut FrameStore) -> AppResult<()> {
+		debug!(
+			"Saving the last {:.1} seconds ({} frames)",
+			self.total_delay.as_secs_f64(),
+			self.frames.len()
+		);
+		while let Some((image, delay)) = self.frames.pop_front() {
+			store.push(image, delay)?;
+		}
+		self.total_delay = Duration::ZERO;
+		self.memory_usage = 0;
+		Ok(())
+	}
+
+	/* Remove the frames in the buffer. */
+	pub fn clear(&mut self) {
+		self.frames.clear();
+		self.total_delay = Duration::ZERO;
+		self.memory_usage = 0;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::Rgba;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_replay_buffer() -> AppResult<()> {
+		let image = Image::new(
+			vec![Rgba::from([0, 0, 0, 255])],
+			false,
+			Geometry::new(0, 0, 1, 1),
+		);
+		let mut replay = ReplayBuffer::new(Duration::from_millis(250), 16);
+		for _ in 0..10 {
+			replay.push(image.clone(), Duration::from_millis(100));
+		}
+		assert_eq!(3, replay.len());
+		assert_eq!(Duration::from_millis(300), replay.total_delay);
+		assert!(!replay.limited);
+		let mut store = FrameStore::new(u64::MAX);
+		replay.drain(&mut store)?;
+		assert_eq!(0, replay.len());
+		assert_eq!(3, store.len());
+		assert_eq!(Duration::from_millis(100), store.get_delay(2));
+		let mut replay = ReplayBuffer::new(Duration::from_secs(10), 8);
+		for _ in 0..10 {
+			replay.push(image.clone(), Duration::from_millis(100));
+		}
+		assert_eq!(2, replay.len());
+		assert!(replay.limited);
+		replay.clear();
+		assert_eq!(0, replay.len());
+		Ok(())
+	}
+}
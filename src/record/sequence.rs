@@ -0,0 +1,163 @@
+use crate::app::AppResult;
+use crate::image::qoi::QoiEncoder;
+use crate::image::Image;
+use crate::record::settings::SequenceFormat;
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+use image::codecs::pnm::{PnmEncoder, PnmSubtype, SampleEncoding};
+use image::{ColorType, ExtendedColorType, ImageEncoder};
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/* Name of the file that contains the frame timestamps */
+pub const MANIFEST_FILE: &str = "manifest.csv";
+
+/* Writer of the frames as numbered image files in a directory */
+#[derive(Debug)]
+pub struct FrameSequence {
+	dir: PathBuf,
+	format: SequenceFormat,
+	manifest: File,
+	timestamp: Duration,
+	frame_count: usize,
+}
+
+impl FrameSequence {
+	/**
+	 * Create a new FrameSequence object.
+	 *
+	 * @param  dir
+	 * @param  format
+	 * @return FrameSequence (Result)
+	 */
+	pub fn new(dir: &Path, format: SequenceFormat) -> AppResult<Self> {
+		fs::create_dir_all(dir)?;
+		let mut manifest = File::create(dir.join(MANIFEST_FILE))?;
+		writeln!(manifest, "frame,file,timestamp,delay")?;
+		Ok(Self {
+			dir: dir.to_path_buf(),
+			format,
+			manifest,
+			timestamp: Duration::ZERO,
+			frame_count: 0,
+		})
+	}
+
+	/**
+	 * Write a frame to the directory and add it to the manifest.
+	 *
+	 * @param  image
+	 * @param  delay
+	 * @return Result
+	 */
+	pub fn write(&mut self, image: &Image, delay: Duration) -> AppResult<()> {
+		let file_name = format!(
+			"frame_{:06}.{}",
+			self.frame_count,
+			self.format.as_extension()
+		);
+		let output = BufWriter::new(File::create(self.dir.join(&file_name))?);
+		let color_type = match self.format {
+			SequenceFormat::Ppm => ColorType::Rgb8,
+			_ if image.has_alpha() => ColorType::Rgba8,
+			_ => ColorType::Rgb8,
+		};
+		let data = image.get_data(if color_type == ColorType::Rgba8 {
+			ExtendedColorType::Rgba8
+		} else {
+			ExtendedColorType::Rgb8
+		});
+		let (width, height) = (image.geometry.width, image.geometry.height);
+		match self.format {
+			SequenceFormat::Png => PngEncoder::new_with_quality(
+				output,
+				CompressionType::Fast,
+				FilterType::Sub,
+			)
+			.write_image(&data, width, height, color_type),
+			SequenceFormat::Qoi => {
+				QoiEncoder::new(output).write_image(&data, width, height, color_type)
+			}
+			SequenceFormat::Ppm => PnmEncoder::new(output)
+				.with_subtype(PnmSubtype::Pixmap(SampleEncoding::Binary))
+				.write_image(&data, width, height, color_type),
+		}?;
+		writeln!(
+			self.manifest,
+			"{},{},{},{}",
+			self.frame_count,
+			file_name,
+			self.timestamp.as_millis(),
+			delay.as_millis()
+		)?;
+		self.timestamp += delay;
+		self.frame_count += 1;
+		Ok(())
+	}
+
+	/**
+	 * Get the number of frames that are written.
+	 *
+	 * @return usize
+	 */
+	pub fn len(&self) -> usize {
+		self.frame_count
+	}
+
+	/* Remove the manifest and the directory if no frames are written. */
+	pub fn clean(self) {
+		if self.frame_count == 0 {
+			drop(self.manifest);
+			if let Err(e) = fs::remove_file(self.dir.join(MANIFEST_FILE))
+				.and_then(|_| fs::remove_dir(&self.dir))
+			{
+				warn!("Failed to remove {:?}: {}", self.dir, e);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::image::geometry::Geometry;
+	use image::Rgba;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_frame_sequence() -> AppResult<()> {
+		let image = Image::new(
+			vec![Rgba::from([255, 0, 0, 255]); 4],
+			false,
+			Geometry::new(0, 0, 2, 2),
+		);
+		for format in [
+			SequenceFormat::Png,
+			SequenceFormat::Qoi,
+			SequenceFormat::Ppm,
+		] {
+			let dir = std::env::temp_dir()
+				.join(format!("menyoki-sequence-{}", format.as_extension()));
+			let mut sequence = FrameSequence::new(&dir, format)?;
+			sequence.write(&image, Duration::from_millis(100))?;
+			sequence.write(&image, Duration::from_millis(50))?;
+			assert_eq!(2, sequence.len());
+			sequence.clean();
+			let extension = format.as_extension();
+			assert_eq!(
+				format!(
+					"frame,file,timestamp,delay\n\
+					0,frame_000000.{extension},0,100\n\
+					1,frame_000001.{extension},100,50\n"
+				),
+				fs::read_to_string(dir.join(MANIFEST_FILE))?
+			);
+			assert!(dir.join(format!("frame_000001.{extension}")).exists());
+			fs::remove_dir_all(&dir)?;
+		}
+		let dir = std::env::temp_dir().join("menyoki-sequence-empty");
+		FrameSequence::new(&dir, SequenceFormat::Png)?.clean();
+		assert!(!dir.exists());
+		Ok(())
+	}
+}
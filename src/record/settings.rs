@@ -2,12 +2,16 @@ use crate::args::matches::ArgMatches;
 use crate::args::parser::ArgParser;
 use crate::image::geometry::Geometry;
 use crate::image::padding::Padding;
+use crate::list::MonitorInfo;
 use crate::util::command::Command;
+use std::fmt;
+use std::time::Duration;
 
 /* Time related recording settings */
 #[derive(Clone, Copy, Debug)]
 pub struct RecordTime {
 	pub duration: Option<f64>,
+	pub replay: Option<f64>,
 	pub countdown: u64,
 	pub timeout: u64,
 	pub interval: u64,
@@ -18,6 +22,7 @@ impl Default for RecordTime {
 	fn default() -> Self {
 		Self {
 			duration: None,
+			replay: None,
 			countdown: 3,
 			timeout: 300,
 			interval: 10,
@@ -30,6 +35,7 @@ impl RecordTime {
 	 * Create a new RecordTime object.
 	 *
 	 * @param  duration (Option)
+	 * @param  replay (Option)
 	 * @param  countdown
 	 * @param  timeout
 	 * @param  interval
@@ -37,12 +43,14 @@ impl RecordTime {
 	 */
 	pub fn new(
 		duration: Option<f64>,
+		replay: Option<f64>,
 		countdown: u64,
 		timeout: u64,
 		interval: u64,
 	) -> Self {
 		Self {
 			duration,
+			replay,
 			countdown,
 			timeout,
 			interval,
@@ -61,6 +69,10 @@ impl RecordTime {
 				duration if duration > 0.0 => Some(duration),
 				_ => Self::default().duration,
 			},
+			match parser.parse("replay", 0.0) {
+				replay if replay > 0.0 => Some(replay),
+				_ => Self::default().replay,
+			},
 			parser.parse("countdown", Self::default().countdown),
 			parser.parse("timeout", Self::default().timeout),
 			parser.parse("interval", Self::default().interval),
@@ -74,10 +86,19 @@ pub struct RecordFlag {
 	pub alpha: bool,
 	pub action_keys: Option<&'static str>,
 	pub cancel_keys: Option<&'static str>,
+	pub pause_keys: Option<&'static str>,
 	pub font: Option<&'static str>,
-	pub monitor: Option<usize>,
+	pub monitor: Option<RecordMonitor>,
 	pub select: bool,
 	pub mouse: bool,
+	pub cursor: bool,
+	pub composite: bool,
+	pub drag: bool,
+	pub freeze: bool,
+	pub follow: bool,
+	pub keys: Option<RecordCorner>,
+	pub clicks: bool,
+	pub session: bool,
 }
 
 /* Default initialization values for RecordFlag */
@@ -87,10 +108,19 @@ impl Default for RecordFlag {
 			alpha: false,
 			action_keys: Some(""),
 			cancel_keys: Some(""),
+			pause_keys: Some(""),
 			font: None,
 			monitor: None,
 			select: true,
 			mouse: false,
+			cursor: false,
+			composite: false,
+			drag: false,
+			freeze: false,
+			follow: false,
+			keys: None,
+			clicks: false,
+			session: false,
 		}
 	}
 }
@@ -102,25 +132,45 @@ impl RecordFlag {
 	 * @param  alpha
 	 * @param  action_keys (Option)
 	 * @param  cancel_keys (Option)
+	 * @param  pause_keys (Option)
 	 * @param  font
 	 * @param  monitor (Option)
 	 * @param  select
 	 * @param  mouse
+	 * @param  cursor
+	 * @param  composite
+	 * @param  drag
+	 * @param  freeze
+	 * @param  follow
+	 * @param  keys (Option)
+	 * @param  clicks
+	 * @param  session
 	 * @return RecordFlag
 	 */
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		alpha: bool,
 		action_keys: Option<&'static str>,
 		cancel_keys: Option<&'static str>,
+		pause_keys: Option<&'static str>,
 		font: &str,
-		monitor: Option<usize>,
+		monitor: Option<RecordMonitor>,
 		select: bool,
 		mouse: bool,
+		cursor: bool,
+		composite: bool,
+		drag: bool,
+		freeze: bool,
+		follow: bool,
+		keys: Option<RecordCorner>,
+		clicks: bool,
+		session: bool,
 	) -> Self {
 		Self {
 			alpha,
 			action_keys,
 			cancel_keys,
+			pause_keys,
 			font: if font.is_empty() {
 				None
 			} else {
@@ -129,10 +179,301 @@ impl RecordFlag {
 			monitor,
 			select,
 			mouse,
+			cursor,
+			composite,
+			drag,
+			freeze,
+			follow,
+			keys,
+			clicks,
+			session,
+		}
+	}
+}
+
+/* Monitor to record, selected by index or RandR output name */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordMonitor {
+	Index(usize),
+	Name(&'static str),
+	All,
+}
+
+/* Display implementation for user-facing output */
+impl fmt::Display for RecordMonitor {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Index(index) => write!(f, "{index}"),
+			Self::Name(name) => write!(f, "{name}"),
+			Self::All => write!(f, "*"),
+		}
+	}
+}
+
+impl RecordMonitor {
+	/**
+	 * Create a RecordMonitor enum from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return RecordMonitor (Option)
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Option<Self> {
+		if matches.is_present("all-monitors") {
+			Some(Self::All)
+		} else {
+			matches
+				.value_of("monitor")
+				.map(|monitor| match monitor.parse() {
+					Ok(index) => Self::Index(index),
+					Err(_) => {
+						Self::Name(Box::leak(monitor.to_string().into_boxed_str()))
+					}
+				})
+		}
+	}
+
+	/**
+	 * Find the monitor in the given list.
+	 *
+	 * @param  monitors
+	 * @return MonitorInfo (Option)
+	 */
+	pub fn find<'a>(&self, monitors: &'a [MonitorInfo]) -> Option<&'a MonitorInfo> {
+		match self {
+			Self::Index(index) => monitors.get(index.checked_sub(1)?),
+			Self::Name(name) => {
+				monitors.iter().find(|monitor| monitor.name == *name)
+			}
+			Self::All => None,
 		}
 	}
 }
 
+/* Corner of the frame to show the pressed keys on */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordCorner {
+	TopLeft,
+	TopRight,
+	BottomLeft,
+	BottomRight,
+}
+
+impl RecordCorner {
+	/**
+	 * Create a RecordCorner enum from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return RecordCorner (Option)
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Option<Self> {
+		if matches.is_present("show-keys") {
+			Some(match matches.value_of("keys-corner") {
+				Some("top-left") => Self::TopLeft,
+				Some("top-right") => Self::TopRight,
+				Some("bottom-right") => Self::BottomRight,
+				_ => Self::BottomLeft,
+			})
+		} else {
+			None
+		}
+	}
+}
+
+/* Fixed-size recording area that follows the mouse pointer */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordViewport {
+	pub size: Geometry,
+	pub easing: f64,
+}
+
+impl RecordViewport {
+	/**
+	 * Create a new RecordViewport object.
+	 *
+	 * @param  size
+	 * @param  easing
+	 * @return RecordViewport
+	 */
+	pub fn new(size: Geometry, easing: f64) -> Self {
+		Self { size, easing }
+	}
+
+	/**
+	 * Create a RecordViewport object from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return RecordViewport (Option)
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Option<Self> {
+		matches.value_of("viewport").map(|size| {
+			Self::new(
+				Geometry::parse(size),
+				matches
+					.value_of("easing")
+					.and_then(|v| v.parse::<f64>().ok())
+					.unwrap_or(0.25)
+					.clamp(0., 1.),
+			)
+		})
+	}
+}
+
+/* Periodic capture settings */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecordTimelapse {
+	pub interval: Duration,
+	pub count: Option<u32>,
+	pub threshold: Option<u32>,
+	pub fps: Option<u32>,
+}
+
+impl RecordTimelapse {
+	/**
+	 * Create a new RecordTimelapse object.
+	 *
+	 * @param  interval
+	 * @param  count (Option)
+	 * @param  threshold (Option)
+	 * @param  fps (Option)
+	 * @return RecordTimelapse
+	 */
+	pub fn new(
+		interval: Duration,
+		count: Option<u32>,
+		threshold: Option<u32>,
+		fps: Option<u32>,
+	) -> Self {
+		Self {
+			interval,
+			count,
+			threshold,
+			fps,
+		}
+	}
+
+	/**
+	 * Create a RecordTimelapse object from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return RecordTimelapse (Option)
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Option<Self> {
+		let interval = matches.value_of("every").and_then(|interval| {
+			Self::parse_interval(interval)
+				.map_err(|e| warn!("Invalid capture interval: {}", e))
+				.ok()
+		});
+		interval.map(|interval| {
+			Self::new(
+				interval,
+				matches
+					.value_of("count")
+					.and_then(|v| v.parse().ok())
+					.filter(|count| *count > 0),
+				matches
+					.value_of("skip-similar")
+					.and_then(|v| v.parse().ok()),
+				matches
+					.value_of("animate")
+					.and_then(|v| v.parse().ok())
+					.filter(|fps| *fps > 0),
+			)
+		})
+	}
+
+	/**
+	 * Parse the interval value with an optional unit. (ms, s, m, h)
+	 *
+	 * @param  interval
+	 * @return Duration (Result)
+	 */
+	pub fn parse_interval(interval: &str) -> Result<Duration, String> {
+		let interval = interval.trim();
+		let (value, unit) = interval.split_at(
+			interval
+				.find(|c: char| c.is_ascii_alphabetic())
+				.unwrap_or(interval.len()),
+		);
+		let value = value
+			.parse::<f64>()
+			.map_err(|_| format!("Invalid interval value: {interval:?}"))?;
+		let seconds = match unit {
+			"ms" => value / 1000.,
+			"" | "s" => value,
+			"m" => value * 60.,
+			"h" => value * 3600.,
+			_ => return Err(format!("Unknown interval unit: {unit:?}")),
+		};
+		match Duration::try_from_secs_f64(seconds) {
+			Ok(duration) if !duration.is_zero() => Ok(duration),
+			Ok(_) => Err(String::from("Interval must be greater than zero")),
+			Err(_) => Err(format!("Invalid interval: {interval:?}")),
+		}
+	}
+}
+
+/* Image format of the frames in a sequence */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceFormat {
+	Png,
+	Qoi,
+	Ppm,
+}
+
+impl SequenceFormat {
+	/**
+	 * Get extension from format.
+	 *
+	 * @return str
+	 */
+	pub fn as_extension(&self) -> &'static str {
+		match self {
+			Self::Png => "png",
+			Self::Qoi => "qoi",
+			Self::Ppm => "ppm",
+		}
+	}
+}
+
+/* Image sequence settings */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecordSequence {
+	pub dir: &'static str,
+	pub format: SequenceFormat,
+}
+
+impl RecordSequence {
+	/**
+	 * Create a new RecordSequence object.
+	 *
+	 * @param  dir
+	 * @param  format
+	 * @return RecordSequence
+	 */
+	pub fn new(dir: &'static str, format: SequenceFormat) -> Self {
+		Self { dir, format }
+	}
+
+	/**
+	 * Create a RecordSequence object from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return RecordSequence (Option)
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Option<Self> {
+		matches.value_of("sequence").map(|dir| {
+			Self::new(
+				Box::leak(dir.to_string().into_boxed_str()),
+				match matches.value_of("sequence-format") {
+					Some("qoi") => SequenceFormat::Qoi,
+					Some("ppm") => SequenceFormat::Ppm,
+					_ => SequenceFormat::Png,
+				},
+			)
+		})
+	}
+}
+
 /* Window to record, with geometric properties  */
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RecordWindow {
@@ -160,9 +501,21 @@ impl RecordWindow {
 			} else {
 				None
 			};
-		if matches.is_present("focus") && !matches.is_present("monitor") {
+		if matches.is_present("focus")
+			&& !matches.is_present("monitor")
+			&& !matches.is_present("all-monitors")
+			&& !matches.is_present("drag")
+			&& !matches.is_present("freeze")
+			&& !matches.is_present("viewport")
+		{
 			Self::Focus(size, matches.is_present("parent"))
-		} else if matches.is_present("root") || matches.is_present("monitor") {
+		} else if matches.is_present("root")
+			|| matches.is_present("monitor")
+			|| matches.is_present("all-monitors")
+			|| matches.is_present("drag")
+			|| matches.is_present("freeze")
+			|| matches.is_present("viewport")
+		{
 			Self::Root(size)
 		} else {
 			Self::Focus(Some(size.unwrap_or_default()), matches.is_present("parent"))
@@ -170,6 +523,62 @@ impl RecordWindow {
 	}
 }
 
+/* Window properties to match for non-interactive selection */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowFilter {
+	Id(u64),
+	Title(&'static str),
+	Class(&'static str),
+	Pid(u32),
+}
+
+/* Display implementation for user-facing output */
+impl fmt::Display for WindowFilter {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Id(xid) => write!(f, "ID {xid:#x}"),
+			Self::Title(title) => write!(f, "title \"{title}\""),
+			Self::Class(class) => write!(f, "class \"{class}\""),
+			Self::Pid(pid) => write!(f, "PID {pid}"),
+		}
+	}
+}
+
+impl WindowFilter {
+	/**
+	 * Create a WindowFilter enum from parsed arguments.
+	 *
+	 * @param  matches
+	 * @return WindowFilter (Option)
+	 */
+	fn from_args(matches: &ArgMatches<'_>) -> Option<Self> {
+		if let Some(xid) = matches.value_of("window-id") {
+			Some(Self::Id(Self::parse_id(xid).unwrap_or_default()))
+		} else if let Some(title) = matches.value_of("window-title") {
+			Some(Self::Title(Box::leak(title.to_string().into_boxed_str())))
+		} else if let Some(class) = matches.value_of("window-class") {
+			Some(Self::Class(Box::leak(class.to_string().into_boxed_str())))
+		} else {
+			matches
+				.value_of("pid")
+				.map(|pid| Self::Pid(pid.parse().unwrap_or_default()))
+		}
+	}
+
+	/**
+	 * Parse the window ID in decimal or hexadecimal format.
+	 *
+	 * @param  xid
+	 * @return u64 (Option)
+	 */
+	pub fn parse_id(xid: &str) -> Option<u64> {
+		match xid.strip_prefix("0x") {
+			Some(hex) => u64::from_str_radix(hex, 16).ok(),
+			None => xid.parse().ok(),
+		}
+	}
+}
+
 /* Recording and window settings */
 #[derive(Clone, Copy, Debug)]
 pub struct RecordSettings {
@@ -180,6 +589,11 @@ pub struct RecordSettings {
 	pub time: RecordTime,
 	pub flag: RecordFlag,
 	pub window: RecordWindow,
+	pub filter: Option<WindowFilter>,
+	pub wait_for_window: Option<&'static str>,
+	pub viewport: Option<RecordViewport>,
+	pub timelapse: Option<RecordTimelapse>,
+	pub sequence: Option<RecordSequence>,
 }
 
 /* Default initialization values for RecordSettings */
@@ -193,6 +607,11 @@ impl Default for RecordSettings {
 			time: RecordTime::default(),
 			flag: RecordFlag::default(),
 			window: RecordWindow::Focus(Some(Geometry::default()), false),
+			filter: None,
+			wait_for_window: None,
+			viewport: None,
+			timelapse: None,
+			sequence: None,
 		}
 	}
 }
@@ -208,8 +627,14 @@ impl RecordSettings {
 	 * @param  time
 	 * @param  flag
 	 * @param  window
+	 * @param  filter (Option)
+	 * @param  wait_for_window (Option)
+	 * @param  viewport (Option)
+	 * @param  timelapse (Option)
+	 * @param  sequence (Option)
 	 * @return RecordSettings
 	 */
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		command: Option<&'static str>,
 		color: u64,
@@ -218,6 +643,11 @@ impl RecordSettings {
 		time: RecordTime,
 		flag: RecordFlag,
 		window: RecordWindow,
+		filter: Option<WindowFilter>,
+		wait_for_window: Option<&'static str>,
+		viewport: Option<RecordViewport>,
+		timelapse: Option<RecordTimelapse>,
+		sequence: Option<RecordSequence>,
 	) -> Self {
 		Self {
 			command,
@@ -227,6 +657,11 @@ impl RecordSettings {
 			time,
 			flag,
 			window,
+			filter,
+			wait_for_window,
+			viewport,
+			timelapse,
+			sequence,
 		}
 	}
 
@@ -291,16 +726,38 @@ impl RecordSettings {
 							.to_string()
 							.into_boxed_str(),
 					)),
+					Some(Box::leak(
+						matches
+							.value_of("pause-keys")
+							.unwrap_or_default()
+							.to_string()
+							.into_boxed_str(),
+					)),
 					matches.value_of("font").unwrap_or_default(),
-					matches.value_of("monitor").and_then(|v| v.parse().ok()),
+					RecordMonitor::from_args(matches),
 					if matches.value_of("size").unwrap_or_default().contains('+') {
 						matches.is_present("select")
 					} else {
 						true
 					},
 					matches.is_present("mouse"),
+					matches.is_present("cursor"),
+					matches.is_present("composite"),
+					matches.is_present("drag"),
+					matches.is_present("freeze"),
+					matches.is_present("follow"),
+					RecordCorner::from_args(matches),
+					matches.is_present("show-clicks"),
+					matches.is_present("session"),
 				),
 				RecordWindow::from_args(matches),
+				WindowFilter::from_args(matches),
+				matches.value_of("wait-for-window").map(|pattern| {
+					&*Box::leak(pattern.to_string().into_boxed_str())
+				}),
+				RecordViewport::from_args(matches),
+				RecordTimelapse::from_args(matches),
+				RecordSequence::from_args(matches),
 			),
 			None => RecordSettings::default(),
 		}
@@ -370,6 +827,11 @@ mod tests {
 					.long("cancel-keys")
 					.takes_value(true),
 			)
+			.arg(
+				Arg::with_name("pause-keys")
+					.long("pause-keys")
+					.takes_value(true),
+			)
 			.arg(Arg::with_name("border").long("border").takes_value(true))
 			.arg(Arg::with_name("padding").long("padding").takes_value(true))
 			.arg(Arg::with_name("size").long("size").takes_value(true))
@@ -378,6 +840,7 @@ mod tests {
 					.long("duration")
 					.takes_value(true),
 			)
+			.arg(Arg::with_name("replay").long("replay").takes_value(true))
 			.arg(
 				Arg::with_name("countdown")
 					.long("countdown")
@@ -393,12 +856,61 @@ mod tests {
 			.arg(Arg::with_name("focus").long("focus"))
 			.arg(Arg::with_name("with-alpha").long("with-alpha"))
 			.arg(Arg::with_name("no-keys").long("no-keys"))
+			.arg(Arg::with_name("cursor").long("cursor"))
+			.arg(Arg::with_name("drag").long("drag"))
+			.arg(Arg::with_name("freeze").long("freeze"))
+			.arg(Arg::with_name("follow").long("follow"))
+			.arg(Arg::with_name("show-keys").long("show-keys"))
+			.arg(Arg::with_name("show-clicks").long("show-clicks"))
+			.arg(
+				Arg::with_name("keys-corner")
+					.long("keys-corner")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("window-id")
+					.long("window-id")
+					.takes_value(true),
+			)
+			.arg(Arg::with_name("monitor").long("monitor").takes_value(true))
+			.arg(
+				Arg::with_name("viewport")
+					.long("viewport")
+					.takes_value(true),
+			)
+			.arg(Arg::with_name("easing").long("easing").takes_value(true))
+			.arg(Arg::with_name("session").long("session"))
+			.arg(
+				Arg::with_name("wait-for-window")
+					.long("wait-for-window")
+					.takes_value(true),
+			)
+			.arg(Arg::with_name("every").long("every").takes_value(true))
+			.arg(Arg::with_name("count").long("count").takes_value(true))
+			.arg(
+				Arg::with_name("skip-similar")
+					.long("skip-similar")
+					.takes_value(true),
+			)
+			.arg(Arg::with_name("animate").long("animate").takes_value(true))
+			.arg(
+				Arg::with_name("sequence")
+					.long("sequence")
+					.takes_value(true),
+			)
+			.arg(
+				Arg::with_name("sequence-format")
+					.long("sequence-format")
+					.takes_value(true),
+			)
 			.get_matches_from(vec![
 				"test",
 				"--action-keys",
 				"LControl-Q,S",
 				"--cancel-keys",
 				"X",
+				"--pause-keys",
+				"LControl-P",
 				"--border",
 				"10",
 				"--padding",
@@ -407,6 +919,8 @@ mod tests {
 				"10x10+10+10",
 				"--duration",
 				"1",
+				"--replay",
+				"5",
 				"--countdown",
 				"2",
 				"--timeout",
@@ -415,12 +929,45 @@ mod tests {
 				"12",
 				"--root",
 				"--with-alpha",
+				"--cursor",
+				"--drag",
+				"--freeze",
+				"--follow",
+				"--show-keys",
+				"--show-clicks",
+				"--keys-corner",
+				"top-right",
+				"--window-id",
+				"0x1e00003",
+				"--monitor",
+				"DP-1",
+				"--viewport",
+				"800x600",
+				"--easing",
+				"1.5",
+				"--session",
+				"--wait-for-window",
+				"^xterm$",
+				"--sequence",
+				"frames",
+				"--sequence-format",
+				"qoi",
+				"--every",
+				"30s",
+				"--count",
+				"120",
+				"--skip-similar",
+				"50",
+				"--animate",
+				"10",
 			]);
 		let record_settings =
 			RecordSettings::from_parser(ArgParser::from_args(&args), "000000");
 		assert_eq!(0x0000_0000, record_settings.color);
 		assert_eq!(10, record_settings.border.unwrap());
 		assert_eq!(Padding::new(10, 0, 0, 10), record_settings.padding);
+		assert_eq!(Some(1.), record_settings.time.duration);
+		assert_eq!(Some(5.), record_settings.time.replay);
 		assert_eq!(2, record_settings.time.countdown);
 		assert_eq!(300, record_settings.time.timeout);
 		assert_eq!(12, record_settings.time.interval);
@@ -429,7 +976,82 @@ mod tests {
 			record_settings.window
 		);
 		assert!(record_settings.flag.alpha);
+		assert!(record_settings.flag.cursor);
+		assert!(record_settings.flag.drag);
+		assert!(record_settings.flag.freeze);
+		assert!(record_settings.flag.follow);
+		assert_eq!(Some(RecordCorner::TopRight), record_settings.flag.keys);
+		assert!(record_settings.flag.clicks);
+		assert!(record_settings.flag.session);
+		assert_eq!(Some(WindowFilter::Id(0x1e0_0003)), record_settings.filter);
+		assert_eq!(Some("^xterm$"), record_settings.wait_for_window);
+		assert_eq!(
+			Some(RecordViewport::new(Geometry::new(0, 0, 800, 600), 1.)),
+			record_settings.viewport
+		);
+		assert_eq!(
+			Some(RecordTimelapse::new(
+				Duration::from_secs(30),
+				Some(120),
+				Some(50),
+				Some(10)
+			)),
+			record_settings.timelapse
+		);
+		assert_eq!(
+			Some(RecordSequence::new("frames", SequenceFormat::Qoi)),
+			record_settings.sequence
+		);
+		assert_eq!("qoi", SequenceFormat::Qoi.as_extension());
+		assert_eq!(
+			Ok(Duration::from_millis(500)),
+			RecordTimelapse::parse_interval("500ms")
+		);
+		assert_eq!(
+			Ok(Duration::from_secs(300)),
+			RecordTimelapse::parse_interval("5m")
+		);
+		assert_eq!(
+			Ok(Duration::from_secs(7200)),
+			RecordTimelapse::parse_interval("2h")
+		);
+		assert_eq!(
+			Ok(Duration::from_millis(2500)),
+			RecordTimelapse::parse_interval("2.5")
+		);
+		for interval in ["30x", "abc", "0", "-5s", "0ms", "99999999999999999999h"] {
+			assert!(RecordTimelapse::parse_interval(interval).is_err());
+		}
+		assert_eq!(
+			Some(RecordMonitor::Name("DP-1")),
+			record_settings.flag.monitor
+		);
+		let monitors = vec![
+			MonitorInfo {
+				name: String::from("HDMI-1"),
+				..MonitorInfo::default()
+			},
+			MonitorInfo {
+				name: String::from("DP-1"),
+				..MonitorInfo::default()
+			},
+		];
+		assert_eq!(
+			Some(&monitors[1]),
+			RecordMonitor::Name("DP-1").find(&monitors)
+		);
+		assert_eq!(Some(&monitors[0]), RecordMonitor::Index(1).find(&monitors));
+		assert_eq!(None, RecordMonitor::Index(0).find(&monitors));
+		assert_eq!(None, RecordMonitor::Name("DP-2").find(&monitors));
+		assert_eq!(None, RecordMonitor::All.find(&monitors));
+		assert_eq!(Some(42), WindowFilter::parse_id("42"));
+		assert_eq!(None, WindowFilter::parse_id("0xZZ"));
+		assert_eq!(
+			"title \"^Firefox$\"",
+			WindowFilter::Title("^Firefox$").to_string()
+		);
 		assert_eq!("LControl-Q,S", record_settings.flag.action_keys.unwrap());
 		assert_eq!("X", record_settings.flag.cancel_keys.unwrap());
+		assert_eq!("LControl-P", record_settings.flag.pause_keys.unwrap());
 	}
 }
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+/* Statistics of the recording */
+#[derive(Debug, Default)]
+pub struct RecordStats {
+	pub frames: usize,
+	pub dropped: usize,
+	latency: Duration,
+}
+
+impl RecordStats {
+	/**
+	 * Count a captured frame.
+	 *
+	 * @param  latency
+	 */
+	pub fn add_frame(&mut self, latency: Duration) {
+		self.frames += 1;
+		self.latency += latency;
+	}
+
+	/**
+	 * Count the frames that are skipped for keeping up with the FPS.
+	 *
+	 * @param  count
+	 */
+	pub fn add_dropped(&mut self, count: u32) {
+		self.dropped += count as usize;
+	}
+
+	/**
+	 * Get the mean time that is spent for capturing a frame.
+	 *
+	 * @return Duration
+	 */
+	pub fn get_mean_latency(&self) -> Duration {
+		match u32::try_from(self.frames) {
+			Ok(frames) if frames != 0 => self.latency / frames,
+			_ => Duration::ZERO,
+		}
+	}
+
+	/**
+	 * Get the FPS value that is achieved while recording.
+	 *
+	 * @param  elapsed
+	 * @return f64
+	 */
+	pub fn get_fps(&self, elapsed: Duration) -> f64 {
+		let elapsed = elapsed.as_secs_f64();
+		if elapsed > 0. {
+			self.frames as f64 / elapsed
+		} else {
+			0.
+		}
+	}
+
+	/**
+	 * Log the statistics of the recording.
+	 *
+	 * @param  fps
+	 * @param  elapsed
+	 */
+	pub fn log(&self, fps: u32, elapsed: Duration) {
+		if self.frames != 0 {
+			info!(
+				"Recorded {} frames at {:.1} FPS (requested: {})",
+				self.frames,
+				self.get_fps(elapsed),
+				fps
+			);
+			info!(
+				"Dropped frames: {}, mean capture latency: {:.1} ms",
+				self.dropped,
+				self.get_mean_latency().as_secs_f64() * 1e3
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_record_stats() {
+		let mut stats = RecordStats::default();
+		assert_eq!(Duration::ZERO, stats.get_mean_latency());
+		assert_eq!(0., stats.get_fps(Duration::ZERO));
+		stats.add_frame(Duration::from_millis(10));
+		stats.add_frame(Duration::from_millis(20));
+		stats.add_dropped(3);
+		assert_eq!(2, stats.frames);
+		assert_eq!(3, stats.dropped);
+		assert_eq!(Duration::from_millis(15), stats.get_mean_latency());
+		assert_eq!(4., stats.get_fps(Duration::from_millis(500)));
+		stats.log(10, Duration::from_secs(1));
+	}
+}
@@ -0,0 +1,102 @@
+use crate::image::geometry::Geometry;
+use crate::record::settings::RecordViewport;
+
+/* Capture area that pans to keep the mouse pointer in view */
+#[derive(Clone, Copy, Debug)]
+pub struct Viewport {
+	settings: RecordViewport,
+	position: Option<(f64, f64)>,
+}
+
+impl Viewport {
+	/**
+	 * Create a new Viewport object.
+	 *
+	 * @param  settings
+	 * @return Viewport
+	 */
+	pub fn new(settings: RecordViewport) -> Self {
+		Self {
+			settings,
+			position: None,
+		}
+	}
+
+	/**
+	 * Move the viewport towards the pointer and get the capture area.
+	 *
+	 * @param  pointer
+	 * @param  bounds
+	 * @return Geometry
+	 */
+	pub fn update(&mut self, pointer: (i32, i32), bounds: Geometry) -> Geometry {
+		let (width, height) = (
+			self.settings.size.width.min(bounds.width),
+			self.settings.size.height.min(bounds.height),
+		);
+		let clamp = |position: f64, start: i32, length: u32, size: u32| {
+			position.clamp(
+				f64::from(start),
+				f64::from(start) + f64::from(length - size),
+			)
+		};
+		let target = (
+			clamp(
+				f64::from(pointer.0) - f64::from(width) / 2.,
+				bounds.x,
+				bounds.width,
+				width,
+			),
+			clamp(
+				f64::from(pointer.1) - f64::from(height) / 2.,
+				bounds.y,
+				bounds.height,
+				height,
+			),
+		);
+		let position = match self.position {
+			Some((x, y)) => (
+				x + (target.0 - x) * self.settings.easing,
+				y + (target.1 - y) * self.settings.easing,
+			),
+			None => target,
+		};
+		self.position = Some(position);
+		Geometry::new(
+			position.0.round() as i32,
+			position.1.round() as i32,
+			width,
+			height,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_viewport() {
+		let bounds = Geometry::new(0, 0, 1920, 1080);
+		let mut viewport =
+			Viewport::new(RecordViewport::new(Geometry::new(0, 0, 800, 600), 0.5));
+		assert_eq!(
+			Geometry::new(560, 240, 800, 600),
+			viewport.update((960, 540), bounds)
+		);
+		assert_eq!(
+			Geometry::new(840, 360, 800, 600),
+			viewport.update((1900, 1000), bounds)
+		);
+		assert_eq!(
+			Geometry::new(980, 420, 800, 600),
+			viewport.update((1900, 1000), bounds)
+		);
+		let mut viewport =
+			Viewport::new(RecordViewport::new(Geometry::new(0, 0, 800, 2000), 1.));
+		assert_eq!(
+			Geometry::new(0, 0, 800, 1080),
+			viewport.update((-50, 10), bounds)
+		);
+	}
+}
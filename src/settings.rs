@@ -6,7 +6,8 @@ use crate::file::format::FileFormat;
 use crate::file::settings::SaveSettings;
 use crate::image::geometry::Geometry;
 use crate::image::settings::{JpgSettings, PngSettings, PnmSettings, WebPSettings};
-use crate::record::settings::{RecordSettings, RecordWindow};
+use crate::list::settings::ListSettings;
+use crate::record::settings::{RecordMonitor, RecordSettings, RecordWindow};
 use crate::util::keys::{ActionKeys, KeyType};
 use crate::util::state::InputState;
 use crate::view::settings::ViewSettings;
@@ -26,6 +27,7 @@ pub struct AppSettings<'a> {
 	pub edit: EditSettings,
 	pub analyze: AnalyzeSettings,
 	pub view: ViewSettings,
+	pub list: ListSettings,
 	pub save: SaveSettings,
 	pub input_state: Option<&'static InputState>,
 	pub window_required: bool,
@@ -52,10 +54,11 @@ impl<'a> AppSettings<'a> {
 			anim: AnimSettings::from_args(args, &save.file.format),
 			split: SplitSettings::from_args(args),
 			png: PngSettings::from_args(args),
-			jpg: JpgSettings::from_args(args),
+			jpg: JpgSettings::from_args(args, &save.file.format),
 			webp: WebPSettings::from_args(args),
 			analyze: AnalyzeSettings::from_args(args, Self::get_color(args)),
 			view: ViewSettings::from_args(args),
+			list: ListSettings::from_args(args),
 			pnm,
 			edit,
 			save,
@@ -118,6 +121,11 @@ impl<'a> AppSettings<'a> {
 					} else {
 						ActionKeys::default(KeyType::CancelKeys)
 					},
+					if let Some(keys) = record.flag.pause_keys {
+						ActionKeys::parse(keys, KeyType::PauseKeys)
+					} else {
+						ActionKeys::default(KeyType::PauseKeys)
+					},
 					record.flag.mouse,
 				)
 				.into_boxed_state(),
@@ -157,10 +165,83 @@ impl<'a> AppSettings<'a> {
 					input_state.cancel_keys.to_string()
 				);
 			}
+			if self.record.flag.pause_keys
+				!= Some(&ActionKeys::default(KeyType::PauseKeys).to_string())
+			{
+				info!(
+					"Using custom pause keys: {}",
+					input_state.pause_keys.to_string()
+				);
+			}
 		}
 		if !self.record.flag.select {
 			self.record.border = None;
 		}
+		if self.record.flag.monitor == Some(RecordMonitor::All)
+			&& self.save.file.path.to_str() == Some("-")
+		{
+			self.record.flag.monitor = None;
+			warn!("Monitors cannot be saved separately to the standard output.")
+		}
+		if self.record.sequence.is_some()
+			&& self.record.flag.monitor == Some(RecordMonitor::All)
+		{
+			self.record.sequence = None;
+			warn!("Image sequences cannot be recorded for each monitor.")
+		}
+		if self.record.flag.composite {
+			if let RecordWindow::Root(_) = self.record.window {
+				self.record.flag.composite = false;
+				warn!("Root window cannot be captured through XComposite.")
+			}
+		}
+		if self.record.flag.follow {
+			if let RecordWindow::Root(_) = self.record.window {
+				self.record.flag.follow = false;
+				warn!("Root window cannot be followed while recording.")
+			}
+		}
+		if self.record.timelapse.is_some() && self.args.is_present("record") {
+			self.record.timelapse = None;
+			warn!("Periodic captures are only available for capturing images.")
+		}
+		if self.record.flag.session && self.save.file.format.is_animation() {
+			self.record.flag.session = false;
+			warn!("Session mode cannot be used while recording.")
+		}
+		if self.record.flag.session
+			&& !self.record.flag.drag
+			&& !self.record.flag.freeze
+			&& matches!(
+				self.record.window,
+				RecordWindow::Focus(None, _) | RecordWindow::Root(None)
+			) {
+			self.record.flag.session = false;
+			warn!("Session mode requires selecting the windows or areas.")
+		}
+		if self.record.flag.session
+			&& (self.record.filter.is_some()
+				|| self.record.wait_for_window.is_some())
+		{
+			self.record.flag.session = false;
+			warn!("Session mode cannot be used with the window filters.")
+		}
+		if self.record.viewport.is_some() && self.record.command.is_some() {
+			self.record.viewport = None;
+			warn!("Viewport cannot be followed while recording a command.")
+		}
+		if self.record.flag.keys.is_some() && self.record.command.is_some() {
+			self.record.flag.keys = None;
+			warn!("Pressed keys cannot be shown while recording a command.")
+		}
+		if self.record.flag.clicks && self.record.command.is_some() {
+			self.record.flag.clicks = false;
+			warn!("Mouse clicks cannot be shown while recording a command.")
+		}
+		if self.record.flag.freeze && self.save.file.format.is_animation() {
+			self.record.flag.freeze = false;
+			warn!("Screen cannot be frozen while recording.")
+		}
 		if self.save.file.format == FileFormat::Ico {
 			self.set_icon_size()
 		}
@@ -192,6 +273,7 @@ impl<'a> AppSettings<'a> {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::record::settings::{RecordSequence, SequenceFormat, WindowFilter};
 	use clap::ArgMatches as Args;
 	use pretty_assertions::assert_eq;
 	use std::env;
@@ -214,5 +296,30 @@ mod tests {
 		settings.record.window =
 			RecordWindow::Focus(Some(Geometry::default()), false);
 		settings.check();
+		settings.record.flag.session = true;
+		settings.record.filter = Some(WindowFilter::Pid(1));
+		settings.check();
+		assert!(!settings.record.flag.session);
+		settings.record.filter = None;
+		settings.save.file.format = FileFormat::Gif;
+		settings.record.flag.session = true;
+		settings.check();
+		assert!(!settings.record.flag.session);
+		settings.save.file.format = FileFormat::Png;
+		settings.record.flag.session = true;
+		settings.record.wait_for_window = Some("^xterm$");
+		settings.check();
+		assert!(!settings.record.flag.session);
+		settings.record.wait_for_window = None;
+		settings.save.file.format = FileFormat::Gif;
+		settings.record.flag.freeze = true;
+		settings.check();
+		assert!(!settings.record.flag.freeze);
+		assert!(!settings.record.flag.drag);
+		settings.record.flag.monitor = Some(RecordMonitor::All);
+		settings.record.sequence =
+			Some(RecordSequence::new("frames", SequenceFormat::Png));
+		settings.check();
+		assert_eq!(None, settings.record.sequence);
 	}
 }
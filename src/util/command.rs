@@ -1,5 +1,5 @@
 use std::io::Error;
-use std::process::Command as OsCommand;
+use std::process::{Child, Command as OsCommand};
 
 /* The command and its arguments */
 #[derive(Debug)]
@@ -31,20 +31,24 @@ impl<'a> Command<'a> {
 		Self { cmd, args }
 	}
 
+	/**
+	 * Start the command without waiting for it to exit.
+	 *
+	 * @return Child (Result)
+	 */
+	pub fn spawn(&self) -> Result<Child, Error> {
+		info!("Running the command...");
+		OsCommand::new(self.cmd).args(&self.args).spawn()
+	}
+
 	/**
 	 * Execute the command and wait for it to exit.
 	 *
 	 * @return Result
 	 */
 	pub fn execute(&self) -> Result<(), Error> {
-		info!("Running the command...");
-		match OsCommand::new(self.cmd).args(&self.args).spawn() {
-			Ok(mut child) => {
-				child.wait()?;
-				Ok(())
-			}
-			Err(e) => Err(e),
-		}
+		self.spawn()?.wait()?;
+		Ok(())
 	}
 }
 
@@ -59,6 +63,10 @@ mod tests {
 		Command::new("sleep", vec!["0.01"]).execute()?;
 		assert!(now.elapsed() >= sleep_time);
 		assert!(Command::from("xyz").execute().is_err());
+		let now = Instant::now();
+		Command::new("sleep", vec!["0.01"]).spawn()?.wait()?;
+		assert!(now.elapsed() >= sleep_time);
+		assert!(Command::from("xyz").spawn().is_err());
 		Ok(())
 	}
 }
@@ -3,10 +3,12 @@ use std::fmt;
 use std::str::FromStr;
 
 /* Types of key bindings. */
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 pub enum KeyType {
 	ActionKeys,
 	CancelKeys,
+	PauseKeys,
 }
 
 /* Operational keys and combinations */
@@ -18,6 +20,9 @@ pub struct ActionKeys {
 /* Alias for cancel keys */
 pub type CancelKeys = ActionKeys;
 
+/* Alias for pause keys */
+pub type PauseKeys = ActionKeys;
+
 /* Display implementation for user-facing output */
 impl fmt::Display for ActionKeys {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -68,6 +73,9 @@ impl ActionKeys {
 					vec![Keycode::Escape],
 				],
 			},
+			KeyType::PauseKeys => Self {
+				key_groups: vec![vec![Keycode::LAlt, Keycode::P]],
+			},
 		}
 	}
 
@@ -163,6 +171,10 @@ mod tests {
 			ActionKeys::default(KeyType::CancelKeys).key_groups,
 			ActionKeys::parse("LCxntrxl-WW", KeyType::CancelKeys).key_groups
 		);
+		assert_eq!(
+			"LAlt-P",
+			ActionKeys::parse("", KeyType::PauseKeys).to_string()
+		);
 		assert_eq!(
 			vec![vec![Keycode::X]],
 			ActionKeys::parse("test,X,...", KeyType::ActionKeys).key_groups
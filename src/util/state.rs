@@ -1,4 +1,4 @@
-use crate::util::keys::{ActionKeys, CancelKeys, KeyType};
+use crate::util::keys::{ActionKeys, CancelKeys, KeyType, PauseKeys};
 use device_query::{DeviceQuery, DeviceState};
 use std::fmt;
 
@@ -7,6 +7,7 @@ pub struct InputState {
 	pub state: DeviceState,
 	pub action_keys: ActionKeys,
 	pub cancel_keys: CancelKeys,
+	pub pause_keys: PauseKeys,
 	check_mouse: bool,
 }
 
@@ -16,6 +17,7 @@ impl Default for InputState {
 		Self::new(
 			ActionKeys::default(KeyType::ActionKeys),
 			CancelKeys::default(KeyType::CancelKeys),
+			PauseKeys::default(KeyType::PauseKeys),
 			false,
 		)
 	}
@@ -40,18 +42,21 @@ impl InputState {
 	 *
 	 * @param  action_keys
 	 * @param  cancel_keys
+	 * @param  pause_keys
 	 * @param  check_mouse
 	 * @return InputState
 	 */
 	pub fn new(
 		action_keys: ActionKeys,
 		cancel_keys: CancelKeys,
+		pause_keys: PauseKeys,
 		check_mouse: bool,
 	) -> Self {
 		Self {
 			state: DeviceState::new(),
 			action_keys,
 			cancel_keys,
+			pause_keys,
 			check_mouse,
 		}
 	}
@@ -87,6 +92,15 @@ impl InputState {
 	pub fn check_cancel_keys(&self) -> bool {
 		self.cancel_keys.check(self.state.get_keys())
 	}
+
+	/**
+	 * Check if the pause keys are pressed.
+	 *
+	 * @return bool
+	 */
+	pub fn check_pause_keys(&self) -> bool {
+		self.pause_keys.check(self.state.get_keys())
+	}
 }
 
 #[cfg(test)]
@@ -98,6 +112,7 @@ mod tests {
 		let input_state = InputState::default().into_boxed_state();
 		assert!(!input_state.check_action());
 		assert!(!input_state.check_cancel_keys());
+		assert!(!input_state.check_pause_keys());
 		assert!(format!("{:?}", input_state).len() > 0);
 	}
 }
@@ -1,7 +1,10 @@
 #[cfg(test)]
 pub mod test;
 
+use crate::app::AppResult;
+use crate::image::geometry::Geometry;
 use crate::image::Image;
+use crate::list::{MonitorInfo, WindowInfo};
 use crate::settings::AppSettings;
 use std::fmt::Debug;
 
@@ -10,12 +13,23 @@ pub trait Access<'a, Window: Capture + Send + Sync + Copy + Debug + 'static> {
 	fn init(settings: &'a AppSettings<'a>) -> Option<Self>
 	where
 		Self: Sized;
-	fn get_window(&mut self) -> Option<Window>;
+	fn get_window(&mut self) -> AppResult<Window>;
+	fn get_windows(&self) -> Vec<WindowInfo>;
+	fn get_monitors(&self) -> Vec<MonitorInfo>;
 }
 
 /* Window methods for capturing an image */
 pub trait Capture {
+	/* Resources that are kept while capturing multiple images */
+	type Session;
 	fn get_image(&self) -> Option<Image>;
+	fn get_session_image(&self, session: &mut Self::Session) -> Option<Image>;
 	fn show_countdown(&self);
+	fn init_capture(&mut self) -> Self::Session;
+	fn update_geometry(&mut self) -> bool;
+	fn get_area(&self) -> Geometry;
+	fn set_area(&mut self, area: Geometry);
+	fn get_root_area(&self) -> Geometry;
+	fn get_monitors(&self) -> Vec<MonitorInfo>;
 	fn release(&self);
 }
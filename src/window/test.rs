@@ -1,5 +1,6 @@
 use crate::image::geometry::Geometry;
 use crate::image::Image;
+use crate::list::MonitorInfo;
 use crate::window::Capture;
 use image::Rgba;
 
@@ -30,6 +31,8 @@ impl TestWindow {
 
 /* Test recording implementation for TestWindow */
 impl Capture for TestWindow {
+	type Session = ();
+
 	/**
 	 * Get the test image.
 	 *
@@ -43,9 +46,72 @@ impl Capture for TestWindow {
 		))
 	}
 
+	/**
+	 * Get the test image without a capture session.
+	 *
+	 * @param  session
+	 * @return Image (Option)
+	 */
+	fn get_session_image(&self, _: &mut Self::Session) -> Option<Image> {
+		self.get_image()
+	}
+
 	/* Do not show countdown for testing window. */
 	fn show_countdown(&self) {}
 
+	/* Do not prepare any resources for capturing. */
+	fn init_capture(&mut self) -> Self::Session {}
+
+	/**
+	 * Testing window is never resized.
+	 *
+	 * @return bool
+	 */
+	fn update_geometry(&mut self) -> bool {
+		false
+	}
+
+	/**
+	 * Get the area of the testing window.
+	 *
+	 * @return Geometry
+	 */
+	fn get_area(&self) -> Geometry {
+		self.geometry
+	}
+
+	/**
+	 * Set the area of the testing window.
+	 *
+	 * @param area
+	 */
+	fn set_area(&mut self, area: Geometry) {
+		self.geometry = area;
+	}
+
+	/**
+	 * Get the area of the testing window.
+	 *
+	 * @return Geometry
+	 */
+	fn get_root_area(&self) -> Geometry {
+		self.geometry
+	}
+
+	/**
+	 * Get the test monitor that covers the window.
+	 *
+	 * @return Vector of MonitorInfo
+	 */
+	fn get_monitors(&self) -> Vec<MonitorInfo> {
+		vec![MonitorInfo {
+			name: String::from("TEST-1"),
+			geometry: self.geometry,
+			rotation: 0,
+			primary: true,
+		}]
+	}
+
 	/* Do not do anything with respect to release. */
 	fn release(&self) {}
 }
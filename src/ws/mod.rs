@@ -1,5 +1,7 @@
 pub mod window;
 
+use crate::app::AppResult;
+use crate::list::{MonitorInfo, WindowInfo};
 use crate::settings::AppSettings;
 use crate::window::Access;
 use crate::ws::window::Window;
@@ -21,9 +23,27 @@ impl<'a> Access<'a, Window> for WindowSystem {
 	/**
 	 * Get the window to record/capture.
 	 *
-	 * @return Window (Option)
+	 * @return Window (Result)
 	 */
-	fn get_window(&mut self) -> Option<Window> {
+	fn get_window(&mut self) -> AppResult<Window> {
+		unimplemented!()
+	}
+
+	/**
+	 * Get the list of windows.
+	 *
+	 * @return Vector of WindowInfo
+	 */
+	fn get_windows(&self) -> Vec<WindowInfo> {
+		unimplemented!()
+	}
+
+	/**
+	 * Get the list of monitors.
+	 *
+	 * @return Vector of MonitorInfo
+	 */
+	fn get_monitors(&self) -> Vec<MonitorInfo> {
 		unimplemented!()
 	}
 }
@@ -1,4 +1,6 @@
+use crate::image::geometry::Geometry;
 use crate::image::Image;
+use crate::list::MonitorInfo;
 use crate::window::Capture;
 
 /* Window implementation */
@@ -19,6 +21,8 @@ impl Window {
 
 /* Methods for recording/capturing the window */
 impl Capture for Window {
+	type Session = ();
+
 	/**
 	 * Get an image of the window.
 	 *
@@ -28,11 +32,71 @@ impl Capture for Window {
 		unimplemented!()
 	}
 
+	/**
+	 * Get an image of the window using the capture session.
+	 *
+	 * @param  session
+	 * @return Image (Option)
+	 */
+	fn get_session_image(&self, _: &mut Self::Session) -> Option<Image> {
+		unimplemented!()
+	}
+
 	/* Show countdown on the window. */
 	fn show_countdown(&self) {
 		unimplemented!()
 	}
 
+	/* Prepare the window for capturing multiple images. */
+	fn init_capture(&mut self) -> Self::Session {
+		unimplemented!()
+	}
+
+	/**
+	 * Update the capture area if the window is resized.
+	 *
+	 * @return bool
+	 */
+	fn update_geometry(&mut self) -> bool {
+		unimplemented!()
+	}
+
+	/**
+	 * Get the capture area of the window.
+	 *
+	 * @return Geometry
+	 */
+	fn get_area(&self) -> Geometry {
+		unimplemented!()
+	}
+
+	/**
+	 * Set the capture area of the window.
+	 *
+	 * @param area
+	 */
+	fn set_area(&mut self, _area: Geometry) {
+		unimplemented!()
+	}
+
+	/**
+	 * Get the capture area relative to the root window.
+	 *
+	 * @return Geometry
+	 */
+	fn get_root_area(&self) -> Geometry {
+		unimplemented!()
+	}
+
+	/**
+	 * Get the monitors that the window is displayed on.
+	 *
+	 * @return Vector of MonitorInfo
+	 */
+	fn get_monitors(&self) -> Vec<MonitorInfo> {
+		unimplemented!()
+	}
+
 	/* Release the window. */
 	fn release(&self) {
 		unimplemented!()
@@ -0,0 +1,64 @@
+use std::os::raw::{c_int, c_ulong};
+use x11::xlib;
+
+/* Redirection mode that lets the X server keep updating the screen */
+const COMPOSITE_REDIRECT_AUTOMATIC: c_int = 0;
+
+/* X Composite extension functions */
+#[link(name = "Xcomposite")]
+extern "C" {
+	fn XCompositeQueryExtension(
+		display: *mut xlib::Display,
+		event_base: *mut c_int,
+		error_base: *mut c_int,
+	) -> xlib::Bool;
+	fn XCompositeRedirectWindow(
+		display: *mut xlib::Display,
+		window: xlib::Window,
+		update: c_int,
+	);
+	fn XCompositeNameWindowPixmap(
+		display: *mut xlib::Display,
+		window: xlib::Window,
+	) -> xlib::Pixmap;
+}
+
+/**
+ * Redirect the window hierarchy to off-screen storage.
+ *
+ * @param  display
+ * @param  xid
+ * @return bool
+ */
+pub fn redirect_window(display: *mut xlib::Display, xid: c_ulong) -> bool {
+	unsafe {
+		let (mut event_base, mut error_base) = (0, 0);
+		if XCompositeQueryExtension(display, &mut event_base, &mut error_base)
+			== xlib::False
+		{
+			warn!("X Composite extension is not available.");
+			return false;
+		}
+		XCompositeRedirectWindow(display, xid, COMPOSITE_REDIRECT_AUTOMATIC);
+		xlib::XSync(display, xlib::False);
+	}
+	debug!("Redirected the window to off-screen storage: {:?}", xid);
+	true
+}
+
+/**
+ * Get the off-screen pixmap that holds the window contents.
+ *
+ * @param  display
+ * @param  xid
+ * @return Pixmap (Option)
+ */
+pub fn get_window_pixmap(
+	display: *mut xlib::Display,
+	xid: c_ulong,
+) -> Option<xlib::Pixmap> {
+	match unsafe { XCompositeNameWindowPixmap(display, xid) } {
+		0 => None,
+		pixmap => Some(pixmap),
+	}
+}
@@ -1,14 +1,19 @@
+use crate::app::{AppError, AppResult};
 use crate::image::geometry::Geometry;
+use crate::list::{MonitorInfo, WindowInfo};
 use crate::record::fps::FpsClock;
-use crate::record::settings::{RecordSettings, RecordWindow};
+use crate::record::settings::{RecordSettings, RecordWindow, WindowFilter};
 use crate::util::state::InputState;
+use crate::window::Capture;
 use crate::x11::window::Window;
 use device_query::{DeviceQuery, Keycode};
-use std::ffi::CString;
+use regex::Regex;
+use std::ffi::{CStr, CString};
 use std::io::{self, Write};
-use std::mem::MaybeUninit;
-use std::os::raw::{c_int, c_ulong};
+use std::mem::{self, MaybeUninit};
+use std::os::raw::{c_char, c_int, c_long, c_short, c_uint, c_ulong, c_void};
 use std::ptr;
+use std::slice;
 use std::thread;
 use std::time::{Duration, Instant};
 use x11::xlib;
@@ -19,6 +24,8 @@ const AREA_CHANGE_FACTOR: u32 = 3;
 const AREA_MAX_WIDTH: u32 = 10;
 /* Maximum height of the selected area */
 const AREA_MAX_HEIGHT: u32 = 10;
+/* Cursor shape to show while dragging (XC_crosshair) */
+const DRAG_CURSOR: c_uint = 34;
 /* Default miscellaneous font */
 const DEFAULT_FONT: &str = "-misc-fixed-*-*-*-*-*-*-*-*-*-*-*-*";
 
@@ -130,6 +137,476 @@ impl Display {
 		};
 	}
 
+	/**
+	 * Get the IDs of all windows in the tree under the given window.
+	 *
+	 * @param  xid
+	 * @return Vector of c_ulong
+	 */
+	fn get_window_tree(&self, xid: c_ulong) -> Vec<c_ulong> {
+		let mut tree = Vec::new();
+		unsafe {
+			let mut root = 0;
+			let mut parent = 0;
+			let mut children = ptr::null_mut();
+			let mut nchildren = 0;
+			if xlib::XQueryTree(
+				self.inner,
+				xid,
+				&mut root,
+				&mut parent,
+				&mut children,
+				&mut nchildren,
+			) != 0 && !children.is_null()
+			{
+				for child in slice::from_raw_parts(children, nchildren as usize) {
+					tree.push(*child);
+					tree.extend(self.get_window_tree(*child));
+				}
+				xlib::XFree(children as *mut c_void);
+			}
+		}
+		tree
+	}
+
+	/**
+	 * Get the IDs of the top-level client windows that are mapped on the screen.
+	 *
+	 * @return Vector of c_ulong
+	 */
+	pub fn get_client_windows(&self) -> Vec<c_ulong> {
+		self.get_window_tree(unsafe { xlib::XDefaultRootWindow(self.inner) })
+			.into_iter()
+			.filter(|xid| self.is_client_window(*xid))
+			.collect()
+	}
+
+	/**
+	 * Get the size of the window.
+	 *
+	 * @param  xid
+	 * @return Geometry
+	 */
+	pub fn get_window_geometry(&self, xid: c_ulong) -> Geometry {
+		let mut root: xlib::Window = 0;
+		let (mut x, mut y, mut width, mut height, mut border_width, mut depth) =
+			(0, 0, 0, 0, 0, 0);
+		unsafe {
+			xlib::XGetGeometry(
+				self.inner,
+				xid,
+				&mut root,
+				&mut x,
+				&mut y,
+				&mut width,
+				&mut height,
+				&mut border_width,
+				&mut depth,
+			);
+		}
+		Geometry::new(0, 0, width, height)
+	}
+
+	/**
+	 * Get the position of the window relative to the root window.
+	 *
+	 * @param  xid
+	 * @return Tuple (i32, i32)
+	 */
+	pub fn get_root_position(&self, xid: c_ulong) -> (i32, i32) {
+		let (mut x, mut y, mut child) = (0, 0, 0);
+		unsafe {
+			xlib::XTranslateCoordinates(
+				self.inner,
+				xid,
+				xlib::XDefaultRootWindow(self.inner),
+				0,
+				0,
+				&mut x,
+				&mut y,
+				&mut child,
+			);
+		}
+		(x, y)
+	}
+
+	/**
+	 * Get the listing information of the window.
+	 *
+	 * @param  xid
+	 * @param  monitors
+	 * @return WindowInfo
+	 */
+	pub fn get_window_info(
+		&self,
+		xid: c_ulong,
+		monitors: &[MonitorInfo],
+	) -> WindowInfo {
+		let (x, y) = self.get_root_position(xid);
+		let size = self.get_window_geometry(xid);
+		let geometry = Geometry::new(x, y, size.width, size.height);
+		WindowInfo {
+			id: xid,
+			title: self.get_window_title(xid),
+			class: self.get_window_class(xid).map(|(_, class)| class),
+			pid: self.get_window_pid(xid),
+			geometry,
+			monitor: monitors
+				.iter()
+				.find(|monitor| monitor.contains(geometry))
+				.map(|monitor| monitor.name.clone()),
+		}
+	}
+
+	/**
+	 * Get the name of the window (WM_NAME).
+	 *
+	 * @param  xid
+	 * @return String (Option)
+	 */
+	pub fn get_window_name(&self, xid: c_ulong) -> Option<String> {
+		unsafe {
+			let mut window_name = ptr::null_mut::<c_char>();
+			if xlib::XFetchName(self.inner, xid, &mut window_name) != 0
+				&& !window_name.is_null()
+			{
+				let name =
+					CStr::from_ptr(window_name).to_string_lossy().into_owned();
+				xlib::XFree(window_name as *mut c_void);
+				Some(name)
+			} else {
+				None
+			}
+		}
+	}
+
+	/**
+	 * Get the value of a window property.
+	 *
+	 * @param  xid
+	 * @param  name
+	 * @param  property_type
+	 * @return Vector of T (Option)
+	 */
+	fn get_window_property<T: Copy>(
+		&self,
+		xid: c_ulong,
+		name: &str,
+		property_type: xlib::Atom,
+	) -> Option<Vec<T>> {
+		let name = CString::new(name).expect("Failed to create CString");
+		unsafe {
+			let property = xlib::XInternAtom(self.inner, name.as_ptr(), 1);
+			if property == 0 {
+				return None;
+			}
+			let mut actual_type = 0;
+			let mut actual_format = 0;
+			let mut nitems = 0;
+			let mut bytes_after = 0;
+			let mut data = ptr::null_mut();
+			if xlib::XGetWindowProperty(
+				self.inner,
+				xid,
+				property,
+				0,
+				1024,
+				xlib::False,
+				property_type,
+				&mut actual_type,
+				&mut actual_format,
+				&mut nitems,
+				&mut bytes_after,
+				&mut data,
+			) != xlib::Success as c_int
+				|| data.is_null()
+			{
+				return None;
+			}
+			let item_size = match actual_format {
+				8 => 1,
+				16 => mem::size_of::<c_short>(),
+				_ => mem::size_of::<c_long>(),
+			};
+			let value = if nitems != 0 && item_size == mem::size_of::<T>() {
+				Some(
+					slice::from_raw_parts(data as *const T, nitems as usize)
+						.to_vec(),
+				)
+			} else {
+				None
+			};
+			xlib::XFree(data as *mut c_void);
+			value
+		}
+	}
+
+	/**
+	 * Get the title of the window using EWMH with a fallback to WM_NAME.
+	 *
+	 * @param  xid
+	 * @return String (Option)
+	 */
+	pub fn get_window_title(&self, xid: c_ulong) -> Option<String> {
+		let utf8_string =
+			CString::new("UTF8_STRING").expect("Failed to create CString");
+		let utf8_string =
+			unsafe { xlib::XInternAtom(self.inner, utf8_string.as_ptr(), 0) };
+		self.get_window_property::<u8>(xid, "_NET_WM_NAME", utf8_string)
+			.map(|v| String::from_utf8_lossy(&v).into_owned())
+			.or_else(|| self.get_window_name(xid))
+	}
+
+	/**
+	 * Get the instance and class names of the window (WM_CLASS).
+	 *
+	 * @param  xid
+	 * @return Tuple (String, String) (Option)
+	 */
+	pub fn get_window_class(&self, xid: c_ulong) -> Option<(String, String)> {
+		unsafe {
+			let mut class_hint = xlib::XClassHint {
+				res_name: ptr::null_mut(),
+				res_class: ptr::null_mut(),
+			};
+			if xlib::XGetClassHint(self.inner, xid, &mut class_hint) == 0 {
+				return None;
+			}
+			let get_string = |v: *mut c_char| {
+				if v.is_null() {
+					String::new()
+				} else {
+					let value = CStr::from_ptr(v).to_string_lossy().into_owned();
+					xlib::XFree(v as *mut c_void);
+					value
+				}
+			};
+			Some((
+				get_string(class_hint.res_name),
+				get_string(class_hint.res_class),
+			))
+		}
+	}
+
+	/**
+	 * Get the ID of the process that owns the window (_NET_WM_PID).
+	 *
+	 * @param  xid
+	 * @return u32 (Option)
+	 */
+	pub fn get_window_pid(&self, xid: c_ulong) -> Option<u32> {
+		self.get_window_property::<c_ulong>(xid, "_NET_WM_PID", xlib::XA_CARDINAL)
+			.and_then(|v| v.first().map(|pid| *pid as u32))
+	}
+
+	/**
+	 * Check if the window is a mapped top-level client (has WM_STATE).
+	 *
+	 * @param  xid
+	 * @return bool
+	 */
+	pub fn is_client_window(&self, xid: c_ulong) -> bool {
+		unsafe {
+			let mut attributes = MaybeUninit::<xlib::XWindowAttributes>::uninit();
+			if xlib::XGetWindowAttributes(self.inner, xid, attributes.as_mut_ptr())
+				== 0 || attributes.assume_init().map_state != xlib::IsViewable
+			{
+				return false;
+			}
+		}
+		self.get_window_property::<c_ulong>(
+			xid,
+			"WM_STATE",
+			xlib::AnyPropertyType as c_ulong,
+		)
+		.is_some()
+	}
+
+	/**
+	 * Check if the window matches the given title regex or class name.
+	 *
+	 * @param  xid
+	 * @param  regex
+	 * @param  pattern
+	 * @return bool
+	 */
+	fn is_matching_window(
+		&self,
+		xid: c_ulong,
+		regex: &Regex,
+		pattern: &str,
+	) -> bool {
+		self.get_window_title(xid)
+			.is_some_and(|v| regex.is_match(&v))
+			|| self
+				.get_window_class(xid)
+				.is_some_and(|(name, class)| name == pattern || class == pattern)
+	}
+
+	/**
+	 * Wait until a top-level window that matches the given pattern is mapped.
+	 *
+	 * @param  pattern (title regex or class)
+	 * @return Window (Result)
+	 */
+	pub fn wait_for_window(&mut self, pattern: &str) -> AppResult<Window> {
+		let regex = Regex::new(pattern)?;
+		let root_window = self.get_root_window();
+		unsafe {
+			xlib::XSelectInput(
+				self.inner,
+				root_window.xid,
+				xlib::SubstructureNotifyMask,
+			);
+		}
+		info!("Waiting for a window that matches \"{}\"...", pattern);
+		let timeout = Duration::from_secs(self.settings.time.timeout);
+		let start_time = Instant::now();
+		let mut window = self
+			.get_client_windows()
+			.into_iter()
+			.find(|xid| self.is_matching_window(*xid, &regex, pattern));
+		let mut watched_windows = Vec::new();
+		let mut poll_fd = libc::pollfd {
+			fd: unsafe { xlib::XConnectionNumber(self.inner) },
+			events: libc::POLLIN,
+			revents: 0,
+		};
+		while window.is_none() {
+			let remaining = timeout.saturating_sub(start_time.elapsed());
+			if remaining.is_zero() {
+				break;
+			}
+			if unsafe { xlib::XPending(self.inner) } == 0 {
+				unsafe {
+					libc::poll(
+						&mut poll_fd,
+						1,
+						remaining.as_millis().min(c_int::MAX as u128) as c_int,
+					)
+				};
+				continue;
+			}
+			let event = unsafe {
+				let mut event = MaybeUninit::<xlib::XEvent>::uninit();
+				xlib::XNextEvent(self.inner, event.as_mut_ptr());
+				event.assume_init()
+			};
+			let xids = match event.get_type() {
+				xlib::MapNotify => {
+					let xid = unsafe { event.map.window };
+					let mut xids = vec![xid];
+					xids.extend(self.get_window_tree(xid));
+					for xid in &xids {
+						unsafe {
+							xlib::XSelectInput(
+								self.inner,
+								*xid,
+								xlib::PropertyChangeMask,
+							);
+						}
+					}
+					watched_windows.extend(&xids);
+					xids
+				}
+				xlib::PropertyNotify => vec![unsafe { event.property.window }],
+				_ => Vec::new(),
+			};
+			window = xids.into_iter().find(|xid| {
+				self.is_client_window(*xid)
+					&& self.is_matching_window(*xid, &regex, pattern)
+			});
+		}
+		unsafe {
+			for xid in watched_windows {
+				xlib::XSelectInput(self.inner, xid, xlib::NoEventMask);
+			}
+			xlib::XSelectInput(self.inner, root_window.xid, xlib::NoEventMask);
+		}
+		match window {
+			Some(xid) => Ok(self.get_matched_window(xid)),
+			None => Err(AppError::WsError(format!(
+				"No window found with \"{}\" (timed out)",
+				pattern
+			))),
+		}
+	}
+
+	/**
+	 * Find the window that matches the given filter.
+	 *
+	 * @param  filter
+	 * @return Window (Result)
+	 */
+	pub fn find_window(&mut self, filter: WindowFilter) -> AppResult<Window> {
+		let windows = match filter {
+			WindowFilter::Id(xid) => self
+				.get_window_tree(unsafe { xlib::XDefaultRootWindow(self.inner) })
+				.into_iter()
+				.filter(|v| *v == xid)
+				.collect(),
+			WindowFilter::Title(title) => {
+				let regex = Regex::new(title)?;
+				self.get_client_windows()
+					.into_iter()
+					.filter(|xid| {
+						self.get_window_title(*xid)
+							.is_some_and(|v| regex.is_match(&v))
+					})
+					.collect()
+			}
+			WindowFilter::Class(class) => self
+				.get_client_windows()
+				.into_iter()
+				.filter(|xid| {
+					self.get_window_class(*xid)
+						.is_some_and(|(name, class_name)| {
+							name == class || class_name == class
+						})
+				})
+				.collect(),
+			WindowFilter::Pid(pid) => self
+				.get_client_windows()
+				.into_iter()
+				.filter(|xid| self.get_window_pid(*xid) == Some(pid))
+				.collect::<Vec<c_ulong>>(),
+		};
+		match windows.as_slice() {
+			[] => Err(AppError::WsError(format!(
+				"No window found with {}",
+				filter
+			))),
+			[xid] => Ok(self.get_matched_window(*xid)),
+			_ => Err(AppError::WsError(format!(
+				"Multiple windows found with {}:{}",
+				filter,
+				windows
+					.iter()
+					.map(|xid| format!(
+						"\n {:#x} -> \"{}\"",
+						xid,
+						self.get_window_title(*xid)
+							.unwrap_or_else(|| String::from("(?)"))
+					))
+					.collect::<String>()
+			))),
+		}
+	}
+
+	/**
+	 * Create the Window object of the matched window ID.
+	 *
+	 * @param  xid
+	 * @return Window
+	 */
+	fn get_matched_window(&mut self, xid: c_ulong) -> Window {
+		if let RecordWindow::Focus(Some(size), _) = self.settings.window {
+			self.update_padding(size, self.get_window_geometry(xid));
+		}
+		debug!("Matched window: {:?}", xid);
+		Window::new(xid, *self)
+	}
+
 	/**
 	 * Get the type of Window given with RecordWindow enum.
 	 *
@@ -156,12 +633,12 @@ impl Display {
 	fn get_window_from_monitor(&mut self) -> (Window, Geometry) {
 		let (window, mut size) = self.get_window();
 		if let RecordWindow::Root(_) = self.settings.window {
-			if let Some(monitor) = self.settings.flag.monitor {
-				let crtc = window.get_crtc_info();
-				let geometry = crtc
-					.get(monitor.checked_sub(1).unwrap_or_default())
-					.expect("Invalid monitor number");
-				size = *geometry;
+			if let Some(geometry) = self.settings.flag.monitor.and_then(|monitor| {
+				monitor
+					.find(&window.get_monitors())
+					.map(|monitor| monitor.geometry)
+			}) {
+				size = geometry;
 				self.settings.padding.left =
 					geometry.x.try_into().unwrap_or_default();
 				self.settings.padding.top =
@@ -270,6 +747,182 @@ impl Display {
 		}
 	}
 
+	/**
+	 * Select an area of the root window by dragging the mouse.
+	 *
+	 * @param  input_state
+	 * @return Window (Option)
+	 */
+	pub fn select_area(&mut self, input_state: &InputState) -> Option<Window> {
+		let root = self.get_root_window();
+		let area = self.drag_area(root, input_state)?;
+		Some(self.get_area_window(area, root.geometry))
+	}
+
+	/**
+	 * Select an area by dragging the mouse over a frozen image of the screen.
+	 *
+	 * @param  input_state
+	 * @return Window (Option)
+	 */
+	pub fn select_frozen_area(
+		&mut self,
+		input_state: &InputState,
+	) -> Option<Window> {
+		let root = self.get_root_window();
+		let pixmap = root.get_snapshot();
+		let overlay = unsafe {
+			let mut attributes =
+				MaybeUninit::<xlib::XSetWindowAttributes>::zeroed().assume_init();
+			attributes.override_redirect = xlib::True;
+			attributes.background_pixmap = pixmap;
+			let xid = xlib::XCreateWindow(
+				self.inner,
+				root.xid,
+				0,
+				0,
+				root.geometry.width,
+				root.geometry.height,
+				0,
+				xlib::CopyFromParent,
+				xlib::InputOutput as c_uint,
+				ptr::null_mut(),
+				xlib::CWOverrideRedirect | xlib::CWBackPixmap,
+				&mut attributes,
+			);
+			xlib::XMapRaised(self.inner, xid);
+			xlib::XSync(self.inner, xlib::False);
+			Window::new(xid, *self)
+		};
+		debug!("Screen frozen: {:?}", root.geometry);
+		let area = self.drag_area(overlay, input_state);
+		unsafe {
+			xlib::XDestroyWindow(self.inner, overlay.xid);
+			xlib::XSync(self.inner, xlib::False);
+		}
+		match area {
+			Some(area) => {
+				let mut window = self.get_area_window(area, root.geometry);
+				window.set_frozen(pixmap);
+				Some(window)
+			}
+			None => {
+				unsafe { xlib::XFreePixmap(self.inner, pixmap) };
+				None
+			}
+		}
+	}
+
+	/**
+	 * Drag the mouse on the given window for selecting an area.
+	 *
+	 * @param  window
+	 * @param  input_state
+	 * @return Geometry (Option)
+	 */
+	fn drag_area(
+		&self,
+		mut window: Window,
+		input_state: &InputState,
+	) -> Option<Geometry> {
+		let font = self.font.unwrap_or_else(|| unsafe {
+			xlib::XLoadQueryFont(
+				self.inner,
+				CString::new(DEFAULT_FONT).unwrap_or_default().as_ptr(),
+			)
+		});
+		let cursor = unsafe {
+			let cursor = xlib::XCreateFontCursor(self.inner, DRAG_CURSOR);
+			xlib::XGrabPointer(
+				self.inner,
+				window.xid,
+				xlib::False,
+				(xlib::ButtonPressMask
+					| xlib::ButtonReleaseMask
+					| xlib::PointerMotionMask) as c_uint,
+				xlib::GrabModeAsync,
+				xlib::GrabModeAsync,
+				window.xid,
+				cursor,
+				xlib::CurrentTime,
+			);
+			cursor
+		};
+		let mut start = None;
+		let mut area = None;
+		let start_time = Instant::now();
+		info!("Click and drag to select an area.");
+		loop {
+			thread::sleep(Duration::from_millis(self.settings.time.interval));
+			let mouse = input_state.state.get_mouse();
+			if input_state.check_cancel_keys() {
+				warn!("User interrupt detected.");
+				area = None;
+				break;
+			} else if start_time.elapsed().as_secs() > self.settings.time.timeout {
+				warn!("The operation timed out.");
+				area = None;
+				break;
+			}
+			let pressed = mouse.button_pressed.get(1).copied().unwrap_or(false);
+			match (start, pressed) {
+				(None, true) => start = Some(mouse.coords),
+				(Some((x, y)), true) => {
+					let geometry = Geometry::new(
+						x.min(mouse.coords.0),
+						y.min(mouse.coords.1),
+						x.abs_diff(mouse.coords.0),
+						y.abs_diff(mouse.coords.1),
+					);
+					if area != Some(geometry) {
+						window.clear_area();
+						window.area = geometry;
+						window.draw_borders();
+						window.show_text_centered(Some(geometry.to_string()), font);
+						info!(" Selected area -> [{}] {:<10}\r#", geometry, " ");
+						io::stdout().flush().expect("Failed to flush stdout");
+						area = Some(geometry);
+					}
+				}
+				(Some(_), false) => {
+					if area.is_some_and(|v| v.width != 0 && v.height != 0) {
+						break;
+					}
+					start = None;
+					area = None;
+				}
+				(None, false) => {}
+			}
+		}
+		info!("\n");
+		unsafe {
+			xlib::XUngrabPointer(self.inner, xlib::CurrentTime);
+			xlib::XFreeCursor(self.inner, cursor);
+		}
+		window.clear_area();
+		area
+	}
+
+	/**
+	 * Get the root window with the padding set to the selected area.
+	 *
+	 * @param  area
+	 * @param  root_geometry
+	 * @return Window
+	 */
+	fn get_area_window(
+		&mut self,
+		area: Geometry,
+		root_geometry: Geometry,
+	) -> Window {
+		debug!("Selected area: {:?}", area);
+		self.settings.window = RecordWindow::Root(Some(area));
+		self.settings.padding.left = area.x.try_into().unwrap_or_default();
+		self.settings.padding.top = area.y.try_into().unwrap_or_default();
+		self.update_padding(area, root_geometry);
+		self.get_root_window()
+	}
+
 	/**
 	 * Update padding to set the given width and height.
 	 *
@@ -377,7 +1030,7 @@ mod tests {
 	#[test]
 	fn test_x11_display() {
 		let mut settings = RecordSettings::default();
-		settings.time = RecordTime::new(Some(0.0), 0, 0, 10);
+		settings.time = RecordTime::new(Some(0.0), None, 0, 0, 10);
 		settings.flag.font = Some(DEFAULT_FONT);
 		let mut display = Display::open(Some(settings)).unwrap();
 		display
@@ -1,9 +1,13 @@
+pub mod composite;
 pub mod display;
+pub mod shm;
 pub mod window;
 
-use crate::record::settings::RecordWindow;
+use crate::app::{AppError, AppResult};
+use crate::list::{MonitorInfo, WindowInfo};
+use crate::record::settings::{RecordMonitor, RecordWindow};
 use crate::settings::AppSettings;
-use crate::window::Access;
+use crate::window::{Access, Capture};
 use crate::x11::display::Display;
 use crate::x11::window::Window;
 use std::ffi::CStr;
@@ -36,11 +40,37 @@ impl<'a> Access<'a, Window> for WindowSystem<'a> {
 	/**
 	 * Get the window to record.
 	 *
-	 * @return Window (Option)
+	 * @return Window (Result)
 	 */
-	fn get_window(&mut self) -> Option<Window> {
+	fn get_window(&mut self) -> AppResult<Window> {
 		debug!("Record window: {:?}", self.settings.record.window);
-		match self.settings.record.window {
+		self.check_monitor()?;
+		if let Some(pattern) = self.settings.record.wait_for_window {
+			return self
+				.display
+				.wait_for_window(pattern)
+				.map(|window| self.redirect_window(window));
+		}
+		if let Some(filter) = self.settings.record.filter {
+			debug!("Window filter: {}", filter);
+			return self
+				.display
+				.find_window(filter)
+				.map(|window| self.redirect_window(window));
+		}
+		let window = match self.settings.record.window {
+			_ if self.settings.record.flag.freeze => {
+				self.display.select_frozen_area(
+					self.settings
+						.input_state
+						.expect("Failed to get the input state"),
+				)
+			}
+			_ if self.settings.record.flag.drag => self.display.select_area(
+				self.settings
+					.input_state
+					.expect("Failed to get the input state"),
+			),
 			RecordWindow::Focus(None, parent) => {
 				self.display.get_focused_window(parent)
 			}
@@ -61,7 +91,82 @@ impl<'a> Access<'a, Window> for WindowSystem<'a> {
 					)
 				}
 			}
+		};
+		window
+			.map(|window| self.redirect_window(window))
+			.ok_or_else(|| {
+				AppError::WsError(String::from("Failed to get the window"))
+			})
+	}
+
+	/**
+	 * Get the list of top-level windows.
+	 *
+	 * @return Vector of WindowInfo
+	 */
+	fn get_windows(&self) -> Vec<WindowInfo> {
+		let monitors = self.get_monitors();
+		self.display
+			.get_client_windows()
+			.into_iter()
+			.map(|xid| self.display.get_window_info(xid, &monitors))
+			.collect()
+	}
+
+	/**
+	 * Get the list of monitors.
+	 *
+	 * @return Vector of MonitorInfo
+	 */
+	fn get_monitors(&self) -> Vec<MonitorInfo> {
+		self.display.get_root_window().get_monitors()
+	}
+}
+
+impl WindowSystem<'_> {
+	/**
+	 * Check if the monitor specified via settings exists.
+	 *
+	 * @return Result
+	 */
+	fn check_monitor(&self) -> AppResult<()> {
+		match self.settings.record.flag.monitor {
+			Some(RecordMonitor::All) | None => Ok(()),
+			Some(monitor) => {
+				let monitors = self.get_monitors();
+				if monitor.find(&monitors).is_some() {
+					Ok(())
+				} else {
+					Err(AppError::WsError(format!(
+						"Monitor not found: {} (available: {})",
+						monitor,
+						monitors
+							.iter()
+							.enumerate()
+							.map(|(i, monitor)| format!(
+								"{}/{}",
+								i + 1,
+								monitor.name
+							))
+							.collect::<Vec<String>>()
+							.join(", ")
+					)))
+				}
+			}
+		}
+	}
+
+	/**
+	 * Redirect the window to off-screen storage if composite mode is enabled.
+	 *
+	 * @param  window
+	 * @return Window
+	 */
+	fn redirect_window(&self, mut window: Window) -> Window {
+		if self.settings.record.flag.composite {
+			window.redirect();
 		}
+		window
 	}
 }
 
@@ -121,17 +226,11 @@ mod tests {
 		let mut settings = AppSettings::new(&matches);
 		settings.record.time.timeout = 1;
 		settings.record.window = RecordWindow::Root(None);
-		assert!(WindowSystem::init(&settings)
-			.unwrap()
-			.get_window()
-			.is_some());
+		assert!(WindowSystem::init(&settings).unwrap().get_window().is_ok());
 		settings.record.window = RecordWindow::Root(Some(Geometry::default()));
 		settings.input_state =
 			Some(Box::leak(InputState::default().into_boxed_state()));
-		assert!(WindowSystem::init(&settings)
-			.unwrap()
-			.get_window()
-			.is_none());
+		assert!(WindowSystem::init(&settings).unwrap().get_window().is_err());
 		settings.record.window = RecordWindow::Focus(None, false);
 		let mut window_system = WindowSystem::init(&settings).unwrap();
 		window_system.display.set_focused_window(
@@ -0,0 +1,178 @@
+use crate::image::geometry::Geometry;
+use std::mem::MaybeUninit;
+use std::os::raw::{c_char, c_uint, c_ulong};
+use std::ptr;
+use std::slice;
+use x11::{xlib, xshm};
+
+/* Link the X11 miscellaneous extension library for MIT-SHM */
+#[link(name = "Xext")]
+extern "C" {}
+
+/* Shared memory segment and the image that is attached to it */
+#[derive(Debug)]
+pub struct ShmImage {
+	display: *mut xlib::Display,
+	inner: *mut xlib::XImage,
+	info: *mut xshm::XShmSegmentInfo,
+	attached: bool,
+	pub geometry: Geometry,
+}
+
+impl ShmImage {
+	/**
+	 * Create a new ShmImage object using the MIT-SHM extension.
+	 *
+	 * @param  display
+	 * @param  xid
+	 * @param  geometry
+	 * @return ShmImage (Option)
+	 */
+	pub fn new(
+		display: *mut xlib::Display,
+		xid: c_ulong,
+		geometry: Geometry,
+	) -> Option<Self> {
+		unsafe {
+			if xshm::XShmQueryExtension(display) == xlib::False {
+				debug!("MIT-SHM extension is not available.");
+				return None;
+			}
+			let mut attributes = MaybeUninit::<xlib::XWindowAttributes>::uninit();
+			if xlib::XGetWindowAttributes(display, xid, attributes.as_mut_ptr()) == 0
+			{
+				return None;
+			}
+			let attributes = attributes.assume_init();
+			let info = Box::into_raw(Box::new(xshm::XShmSegmentInfo {
+				shmseg: 0,
+				shmid: -1,
+				shmaddr: ptr::null_mut(),
+				readOnly: xlib::False,
+			}));
+			let mut shm_image = Self {
+				display,
+				inner: xshm::XShmCreateImage(
+					display,
+					attributes.visual,
+					attributes.depth.try_into().unwrap_or_default(),
+					xlib::ZPixmap,
+					ptr::null_mut(),
+					info,
+					geometry.width,
+					geometry.height,
+				),
+				info,
+				attached: false,
+				geometry,
+			};
+			if shm_image.inner.is_null() || !shm_image.attach() {
+				warn!("Failed to create a shared memory segment.");
+				return None;
+			}
+			debug!("Attached a shared memory segment ({}).", geometry);
+			Some(shm_image)
+		}
+	}
+
+	/**
+	 * Allocate the shared memory segment and attach it to the X server.
+	 *
+	 * @return bool
+	 */
+	unsafe fn attach(&mut self) -> bool {
+		let size = usize::try_from((*self.inner).bytes_per_line).unwrap_or_default()
+			* usize::try_from((*self.inner).height).unwrap_or_default();
+		let shmid = libc::shmget(libc::IPC_PRIVATE, size, libc::IPC_CREAT | 0o600);
+		if shmid < 0 {
+			return false;
+		}
+		(*self.info).shmid = shmid;
+		let shmaddr = libc::shmat(shmid, ptr::null(), 0);
+		if shmaddr as isize == -1 {
+			libc::shmctl(shmid, libc::IPC_RMID, ptr::null_mut());
+			return false;
+		}
+		(*self.info).shmaddr = shmaddr as *mut c_char;
+		(*self.inner).data = shmaddr as *mut c_char;
+		self.attached = xshm::XShmAttach(self.display, self.info) != xlib::False;
+		xlib::XSync(self.display, xlib::False);
+		libc::shmctl(shmid, libc::IPC_RMID, ptr::null_mut());
+		self.attached
+	}
+
+	/**
+	 * Get the image data of the given window area.
+	 *
+	 * @param  xid
+	 * @param  area
+	 * @return Slice of BGRA values (Option)
+	 */
+	pub fn get_data(&self, xid: c_ulong, area: Geometry) -> Option<&[[u8; 4]]> {
+		if area.width != self.geometry.width || area.height != self.geometry.height {
+			return None;
+		}
+		unsafe {
+			if xshm::XShmGetImage(
+				self.display,
+				xid,
+				self.inner,
+				area.x,
+				area.y,
+				xlib::XAllPlanes() as c_uint,
+			) == xlib::False
+			{
+				return None;
+			}
+			Some(slice::from_raw_parts(
+				(*self.inner).data as *const [u8; 4],
+				area.width as usize * area.height as usize,
+			))
+		}
+	}
+}
+
+/* Detach and free the shared memory segment on drop */
+impl Drop for ShmImage {
+	fn drop(&mut self) {
+		unsafe {
+			if self.attached {
+				xshm::XShmDetach(self.display, self.info);
+				xlib::XSync(self.display, xlib::False);
+			}
+			if !self.inner.is_null() {
+				xlib::XDestroyImage(self.inner);
+			}
+			if !(*self.info).shmaddr.is_null() {
+				libc::shmdt((*self.info).shmaddr as *const libc::c_void);
+			}
+			drop(Box::from_raw(self.info));
+		}
+		debug!("Detached the shared memory segment.");
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "test-ws")]
+mod tests {
+	use super::*;
+	use crate::window::Capture;
+	use crate::x11::display::Display;
+	use pretty_assertions::assert_eq;
+	#[test]
+	fn test_x11_shm() {
+		let display = Display::open(None).unwrap();
+		let window = display.get_root_window();
+		let shm_image =
+			ShmImage::new(display.inner, window.xid, window.area).unwrap();
+		assert_eq!(
+			1920 * 1080,
+			shm_image.get_data(window.xid, window.area).unwrap().len()
+		);
+		assert!(shm_image
+			.get_data(window.xid, Geometry::new(0, 0, 1, 1))
+			.is_none());
+		drop(shm_image);
+		window.release();
+	}
+}
@@ -1,17 +1,20 @@
 use crate::image::geometry::Geometry;
 use crate::image::Image;
+use crate::list::MonitorInfo;
 use crate::record::fps::FpsClock;
 use crate::window::Capture;
+use crate::x11::composite;
 use crate::x11::display::Display;
+use crate::x11::shm::ShmImage;
 use image::Rgba;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use std::io::{self, Write};
 use std::mem::MaybeUninit;
-use std::os::raw::{c_char, c_uint, c_ulong};
+use std::os::raw::{c_int, c_uint, c_ulong, c_void};
 use std::ptr;
 use std::slice;
-use x11::{xlib, xrandr};
+use x11::{xfixes, xlib, xrandr};
 
 /* Maximum height of the text to show on window */
 const MAX_TEXT_HEIGHT: u32 = 40;
@@ -28,6 +31,8 @@ pub struct Window {
 	gc: xlib::GC,
 	pub geometry: Geometry,
 	pub area: Geometry,
+	redirected: bool,
+	frozen: Option<c_ulong>,
 }
 
 /* Implementations for thread-safe usage */
@@ -63,6 +68,8 @@ impl Window {
 				gc: ptr::null::<xlib::GC>() as xlib::GC,
 				geometry: Geometry::default(),
 				area: Geometry::default(),
+				redirected: false,
+				frozen: None,
 			}
 			.set_geometry()
 			.set_gc()
@@ -75,21 +82,7 @@ impl Window {
 	 * @return Geometry
 	 */
 	unsafe fn get_geometry(&self) -> Geometry {
-		let mut root: xlib::Window = 0;
-		let (mut x, mut y, mut width, mut height, mut border_width, mut depth) =
-			(0, 0, 0, 0, 0, 0);
-		xlib::XGetGeometry(
-			self.display.inner,
-			self.xid,
-			&mut root,
-			&mut x,
-			&mut y,
-			&mut width,
-			&mut height,
-			&mut border_width,
-			&mut depth,
-		);
-		Geometry::new(0, 0, width, height)
+		self.display.get_window_geometry(self.xid)
 	}
 
 	/**
@@ -165,61 +158,13 @@ impl Window {
 		}
 	}
 
-	/**
-	 * Get CRTC (VDC) information of the window.
-	 *
-	 * @param Vector of Geometry
-	 */
-	pub fn get_crtc_info(&self) -> Vec<Geometry> {
-		let mut crtc_info = Vec::new();
-		unsafe {
-			let resources =
-				xrandr::XRRGetScreenResources(self.display.inner, self.xid);
-			for crtc in slice::from_raw_parts(
-				(*resources).crtcs,
-				(*resources).ncrtc.try_into().unwrap_or_default(),
-			)
-			.iter()
-			.map(|v| xrandr::XRRGetCrtcInfo(self.display.inner, resources, *v))
-			{
-				if (*crtc).noutput > 0 {
-					crtc_info.push(Geometry::new(
-						(*crtc).x,
-						(*crtc).y,
-						(*crtc).width,
-						(*crtc).height,
-					));
-				}
-				xrandr::XRRFreeCrtcInfo(crtc);
-			}
-			xrandr::XRRFreeScreenResources(resources);
-		}
-		crtc_info
-	}
-
 	/**
 	 * Get the name of the window.
 	 *
 	 * @return String (Option)
 	 */
 	pub fn get_name(&self) -> Option<String> {
-		unsafe {
-			let mut window_name = MaybeUninit::<*mut c_char>::uninit();
-			if xlib::XFetchName(
-				self.display.inner,
-				self.xid,
-				window_name.as_mut_ptr(),
-			) != 0
-			{
-				Some(
-					CString::from_raw(*window_name.as_ptr())
-						.into_string()
-						.unwrap_or_default(),
-				)
-			} else {
-				None
-			}
-		}
+		self.display.get_window_name(self.xid)
 	}
 
 	/* Draw a rectangle inside the window. */
@@ -377,20 +322,39 @@ impl Window {
 		}
 		trace!("Ungrabbed the keys of {:?}", self.xid);
 	}
-}
 
-/* Capture implementation for X11 Window */
-impl Capture for Window {
 	/**
-	 * Get the image of the window.
+	 * Get the image of the window area from the given drawable.
+	 *
+	 * The shared memory segment (if exists) is recreated when the
+	 * size of the area changes.
 	 *
+	 * @param  drawable
+	 * @param  shm_image (Option)
 	 * @return Image (Option)
 	 */
-	fn get_image(&self) -> Option<Image> {
+	fn get_drawable_image(
+		&self,
+		drawable: c_ulong,
+		shm_image: &mut Option<ShmImage>,
+	) -> Option<Image> {
+		if shm_image.as_ref().is_some_and(|shm_image| {
+			(shm_image.geometry.width, shm_image.geometry.height)
+				!= (self.area.width, self.area.height)
+		}) {
+			*shm_image = ShmImage::new(self.display.inner, self.xid, self.area);
+		}
+		if let Some(image) = shm_image
+			.as_ref()
+			.and_then(|shm_image| shm_image.get_data(drawable, self.area))
+			.map(|data| self.get_image_from_data(data))
+		{
+			return Some(image);
+		}
 		unsafe {
 			let window_image = xlib::XGetImage(
 				self.display.inner,
-				self.xid,
+				drawable,
 				self.area.x,
 				self.area.y,
 				self.area.width,
@@ -400,25 +364,204 @@ impl Capture for Window {
 			);
 			if !window_image.is_null() {
 				let image = &mut *window_image;
-				let data = slice::from_raw_parts(
+				let image_data = self.get_image_from_data(slice::from_raw_parts(
 					image.data as *const [u8; 4],
 					image.width as usize * image.height as usize,
-				)
-				.to_vec();
+				));
 				xlib::XDestroyImage(window_image);
-				Some(Image::new(
-					data.iter()
-						.map(|bgra| Rgba::from([bgra[2], bgra[1], bgra[0], bgra[3]]))
-						.collect(),
-					self.display.settings.flag.alpha,
-					self.area,
-				))
+				Some(image_data)
 			} else {
 				None
 			}
 		}
 	}
 
+	/* Redirect the window to off-screen storage for capturing it while obscured. */
+	pub fn redirect(&mut self) {
+		self.redirected = composite::redirect_window(self.display.inner, self.xid);
+	}
+
+	/**
+	 * Copy the current contents of the window into a pixmap.
+	 *
+	 * @return Pixmap
+	 */
+	pub fn get_snapshot(&self) -> c_ulong {
+		unsafe {
+			let screen = xlib::XDefaultScreen(self.display.inner);
+			let pixmap = xlib::XCreatePixmap(
+				self.display.inner,
+				self.xid,
+				self.geometry.width,
+				self.geometry.height,
+				xlib::XDefaultDepth(self.display.inner, screen) as c_uint,
+			);
+			let mut values = MaybeUninit::<xlib::XGCValues>::zeroed().assume_init();
+			values.subwindow_mode = xlib::IncludeInferiors;
+			let gc = xlib::XCreateGC(
+				self.display.inner,
+				self.xid,
+				xlib::GCSubwindowMode as c_ulong,
+				&mut values,
+			);
+			xlib::XCopyArea(
+				self.display.inner,
+				self.xid,
+				pixmap,
+				gc,
+				0,
+				0,
+				self.geometry.width,
+				self.geometry.height,
+				0,
+				0,
+			);
+			xlib::XFreeGC(self.display.inner, gc);
+			xlib::XSync(self.display.inner, xlib::False);
+			pixmap
+		}
+	}
+
+	/**
+	 * Capture the window from a frozen snapshot instead of the screen.
+	 *
+	 * @param pixmap
+	 */
+	pub fn set_frozen(&mut self, pixmap: c_ulong) {
+		self.frozen = Some(pixmap);
+	}
+
+	/**
+	 * Create an Image object from the BGRA data of the window.
+	 *
+	 * @param  data
+	 * @return Image
+	 */
+	fn get_image_from_data(&self, data: &[[u8; 4]]) -> Image {
+		let mut image = Image::new(
+			data.iter()
+				.map(|bgra| Rgba::from([bgra[2], bgra[1], bgra[0], bgra[3]]))
+				.collect(),
+			self.display.settings.flag.alpha,
+			self.area,
+		);
+		if self.display.settings.flag.cursor {
+			if let Some((cursor, x, y)) = self.get_cursor_image() {
+				image.blend(&cursor, x, y);
+			}
+		}
+		image
+	}
+
+	/**
+	 * Get the position of the window relative to the root window.
+	 *
+	 * @return Tuple (i32, i32)
+	 */
+	pub fn get_root_position(&self) -> (i32, i32) {
+		self.display.get_root_position(self.xid)
+	}
+
+	/**
+	 * Get the image of the mouse cursor and its position on the window area.
+	 *
+	 * @return Tuple (Image, i32, i32) (Option)
+	 */
+	fn get_cursor_image(&self) -> Option<(Image, i32, i32)> {
+		unsafe {
+			let (mut event_base, mut error_base) = (0, 0);
+			if xfixes::XFixesQueryExtension(
+				self.display.inner,
+				&mut event_base,
+				&mut error_base,
+			) == xlib::False
+			{
+				return None;
+			}
+			let cursor = xfixes::XFixesGetCursorImage(self.display.inner);
+			if cursor.is_null() {
+				return None;
+			}
+			let cursor_image = &*cursor;
+			let data = slice::from_raw_parts(
+				cursor_image.pixels,
+				usize::from(cursor_image.width) * usize::from(cursor_image.height),
+			)
+			.iter()
+			.map(|pixel| {
+				let argb = (*pixel & 0xFFFF_FFFF) as u32;
+				let alpha = (argb >> 24) as u8;
+				let unmultiply = |value: u32| {
+					if alpha == 0 {
+						0
+					} else {
+						((value & 0xFF) * 255 / u32::from(alpha)).min(255) as u8
+					}
+				};
+				Rgba::from([
+					unmultiply(argb >> 16),
+					unmultiply(argb >> 8),
+					unmultiply(argb),
+					alpha,
+				])
+			})
+			.collect();
+			let (root_x, root_y) = self.get_root_position();
+			let position = (
+				i32::from(cursor_image.x)
+					- i32::from(cursor_image.xhot)
+					- (root_x + self.area.x),
+				i32::from(cursor_image.y)
+					- i32::from(cursor_image.yhot)
+					- (root_y + self.area.y),
+			);
+			let geometry = Geometry::new(
+				0,
+				0,
+				cursor_image.width.into(),
+				cursor_image.height.into(),
+			);
+			xlib::XFree(cursor as *mut c_void);
+			Some((Image::new(data, true, geometry), position.0, position.1))
+		}
+	}
+}
+
+/* Capture implementation for X11 Window */
+impl Capture for Window {
+	type Session = Option<ShmImage>;
+
+	/**
+	 * Get the image of the window.
+	 *
+	 * @return Image (Option)
+	 */
+	fn get_image(&self) -> Option<Image> {
+		self.get_session_image(&mut None)
+	}
+
+	/**
+	 * Get the image of the window using the shared memory segment.
+	 *
+	 * @param  session
+	 * @return Image (Option)
+	 */
+	fn get_session_image(&self, session: &mut Self::Session) -> Option<Image> {
+		if let Some(pixmap) = self.frozen {
+			return self.get_drawable_image(pixmap, session);
+		}
+		let pixmap = if self.redirected {
+			composite::get_window_pixmap(self.display.inner, self.xid)
+		} else {
+			None
+		};
+		let image = self.get_drawable_image(pixmap.unwrap_or(self.xid), session);
+		if let Some(pixmap) = pixmap {
+			unsafe { xlib::XFreePixmap(self.display.inner, pixmap) };
+		}
+		image
+	}
+
 	/* Show a countdown on the corner of window. */
 	fn show_countdown(&self) {
 		if self.display.settings.time.countdown != 0 {
@@ -452,10 +595,162 @@ impl Capture for Window {
 		self.clear_area();
 	}
 
+	/* Attach a shared memory segment for capturing the window area. */
+	fn init_capture(&mut self) -> Self::Session {
+		if self.display.settings.flag.follow {
+			unsafe {
+				xlib::XSelectInput(
+					self.display.inner,
+					self.xid,
+					xlib::StructureNotifyMask,
+				)
+			};
+		}
+		let shm_image = ShmImage::new(self.display.inner, self.xid, self.area);
+		if shm_image.is_none() {
+			debug!("Falling back to XGetImage for capturing.");
+		}
+		shm_image
+	}
+
+	/**
+	 * Update the capture area if the window is resized.
+	 *
+	 * @return bool
+	 */
+	fn update_geometry(&mut self) -> bool {
+		let mut size = None;
+		unsafe {
+			let mut event = MaybeUninit::<xlib::XEvent>::uninit();
+			while xlib::XCheckTypedWindowEvent(
+				self.display.inner,
+				self.xid,
+				xlib::ConfigureNotify,
+				event.as_mut_ptr(),
+			) != 0
+			{
+				let event = event.assume_init().configure;
+				size = Some((event.width, event.height));
+			}
+			let resized = size.is_some_and(|(width, height)| {
+				i64::from(width) != i64::from(self.geometry.width)
+					|| i64::from(height) != i64::from(self.geometry.height)
+			});
+			if resized {
+				self.set_geometry();
+				debug!("Window resized: {:?}", self.area);
+			}
+			resized
+		}
+	}
+
+	/**
+	 * Get the capture area of the window.
+	 *
+	 * @return Geometry
+	 */
+	fn get_area(&self) -> Geometry {
+		self.area
+	}
+
+	/**
+	 * Set the capture area of the window.
+	 *
+	 * @param area
+	 */
+	fn set_area(&mut self, area: Geometry) {
+		self.area = area;
+	}
+
+	/**
+	 * Get the capture area relative to the root window.
+	 *
+	 * @return Geometry
+	 */
+	fn get_root_area(&self) -> Geometry {
+		let (x, y) = self.get_root_position();
+		Geometry::new(
+			x + self.area.x,
+			y + self.area.y,
+			self.area.width,
+			self.area.height,
+		)
+	}
+
+	/**
+	 * Get the monitors (enabled CRTCs) in the order of the screen resources.
+	 *
+	 * @return Vector of MonitorInfo
+	 */
+	fn get_monitors(&self) -> Vec<MonitorInfo> {
+		let mut monitors = Vec::new();
+		unsafe {
+			let resources =
+				xrandr::XRRGetScreenResources(self.display.inner, self.xid);
+			if resources.is_null() {
+				return monitors;
+			}
+			let primary = xrandr::XRRGetOutputPrimary(self.display.inner, self.xid);
+			for crtc in slice::from_raw_parts(
+				(*resources).crtcs,
+				(*resources).ncrtc.try_into().unwrap_or_default(),
+			)
+			.iter()
+			.map(|v| xrandr::XRRGetCrtcInfo(self.display.inner, resources, *v))
+			{
+				if crtc.is_null() {
+					continue;
+				}
+				if (*crtc).noutput > 0 {
+					let outputs = slice::from_raw_parts(
+						(*crtc).outputs,
+						(*crtc).noutput.try_into().unwrap_or_default(),
+					);
+					let output_info = xrandr::XRRGetOutputInfo(
+						self.display.inner,
+						resources,
+						outputs[0],
+					);
+					let name = if output_info.is_null() {
+						String::new()
+					} else {
+						let name = CStr::from_ptr((*output_info).name)
+							.to_string_lossy()
+							.into_owned();
+						xrandr::XRRFreeOutputInfo(output_info);
+						name
+					};
+					monitors.push(MonitorInfo {
+						name,
+						geometry: Geometry::new(
+							(*crtc).x,
+							(*crtc).y,
+							(*crtc).width,
+							(*crtc).height,
+						),
+						rotation: match c_int::from((*crtc).rotation) & 0xf {
+							xrandr::RR_Rotate_90 => 90,
+							xrandr::RR_Rotate_180 => 180,
+							xrandr::RR_Rotate_270 => 270,
+							_ => 0,
+						},
+						primary: outputs.contains(&primary),
+					});
+				}
+				xrandr::XRRFreeCrtcInfo(crtc);
+			}
+			xrandr::XRRFreeScreenResources(resources);
+		}
+		monitors
+	}
+
 	/* Close the display */
 	fn release(&self) {
 		trace!("Display closed.");
 		unsafe {
+			if let Some(pixmap) = self.frozen {
+				xlib::XFreePixmap(self.display.inner, pixmap);
+			}
 			xlib::XCloseDisplay(self.display.inner);
 		}
 	}
@@ -474,7 +769,7 @@ mod tests {
 	#[test]
 	fn test_x11_window() {
 		let mut settings = RecordSettings::default();
-		settings.time = RecordTime::new(Some(0.0), 1, 0, 10);
+		settings.time = RecordTime::new(Some(0.0), None, 1, 0, 10);
 		let display = Display::open(Some(settings)).unwrap();
 		let window = display.get_root_window();
 		unsafe {
@@ -489,7 +784,10 @@ mod tests {
 		window.draw_borders();
 		window.show_countdown();
 		window.clear_area();
-		assert_eq!(Geometry::new(0, 0, 1920, 1080), window.get_crtc_info()[0]);
+		assert_eq!(
+			Geometry::new(0, 0, 1920, 1080),
+			window.get_monitors()[0].geometry
+		);
 		assert_eq!(0, unsafe { window.get_parent() }.unwrap().xid);
 		assert_eq!(
 			"\n Window title  -> \"root-window\"\n Window size   -> [1920x1080]",